@@ -8,13 +8,33 @@ use cosmic::{
     surface,
     widget::container,
 };
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 use sysinfo::{
     CpuRefreshKind, Disk, DiskRefreshKind, Disks, MemoryRefreshKind, Networks, RefreshKind, System,
 };
 
 use crate::{
-    components::gpu::Gpus,
+    components::{
+        cgroup::Cgroup,
+        cputimes::CpuTimes,
+        custom::Custom,
+        diskstats::DiskStats,
+        drives::DriveData,
+        fans::Fans,
+        gpu::Gpus,
+        interfaces::InterfaceStats,
+        kernel::KernelActivity,
+        load::LoadData,
+        meminfo::MemInfo,
+        processes::ProcessData,
+        procfs::read_table,
+        psi::{Pressures, PsiResource},
+        rapl::{PowerDomain, Rapl},
+        sockets::SocketStats,
+        vmstat::VmStat,
+        wifi::{BitrateReader, WifiData},
+        zram::CompressedSwap,
+    },
     config::{ComponentConfig, Config, config_subscription},
     history::History,
 };
@@ -33,20 +53,113 @@ pub struct SystemMonitorApplet {
     pub gpus: Gpus,
     /// percentage global cpu used between refreshes
     pub global_cpu: History<f32>,
+    pub cpu_times: CpuTimes,
+    /// clock ticks spent in user mode between refreshes. (DOES NOT STORE RATE)
+    pub cpu_user: History,
+    /// clock ticks spent in system mode between refreshes. (DOES NOT STORE RATE)
+    pub cpu_system: History,
+    /// clock ticks spent waiting for I/O between refreshes. (DOES NOT STORE RATE)
+    pub cpu_iowait: History,
     pub ram: History,
     pub swap: History,
+    pub meminfo: MemInfo,
+    pub ram_available: History,
+    /// used, shared, buffers, cache and dirty memory, as in [`MemInfo`]
+    pub ram_breakdown: [History; 5],
+    pub compressed_swap: CompressedSwap,
+    /// swap in zram and zswap before compression
+    pub swap_original: History,
+    /// RAM used by zram and zswap to store the compressed swap
+    pub swap_compressed: History,
+    pub vmstat: VmStat,
+    /// amount read from swap between refreshes. (DOES NOT STORE RATE)
+    pub swap_in: History,
+    /// amount written to swap between refreshes. (DOES NOT STORE RATE)
+    pub swap_out: History,
+    /// major page faults between refreshes. (DOES NOT STORE RATE)
+    pub major_faults: History,
+    /// OOM kills between refreshes. (DOES NOT STORE RATE)
+    pub oom_kills: History,
     /// amount uploaded between refresh of `sysinfo::Nets`. (DOES NOT STORE RATE)
     pub upload: History,
     /// amount downloaded between refresh of `sysinfo::Nets`. (DOES NOT STORE RATE)
     pub download: History,
+    pub interfaces: InterfaceStats,
+    /// packets received between refreshes. (DOES NOT STORE RATE)
+    pub packets_received: History,
+    /// packets transmitted between refreshes. (DOES NOT STORE RATE)
+    pub packets_transmitted: History,
+    /// packet errors between refreshes. (DOES NOT STORE RATE)
+    pub packet_errors: History,
+    /// packets dropped between refreshes. (DOES NOT STORE RATE)
+    pub packet_drops: History,
+    pub sockets: SocketStats,
+    pub tcp_established: History,
+    pub tcp_listening: History,
+    /// TCP retransmits between refreshes. (DOES NOT STORE RATE)
+    pub tcp_retransmits: History,
+    /// UDP errors between refreshes. (DOES NOT STORE RATE)
+    pub udp_errors: History,
     /// amount read between refresh of `sysinfo::Disks`. (DOES NOT STORE RATE)
     pub disk_read: History,
     /// amount written between refresh of `sysinfo::Disks`. (DOES NOT STORE RATE)
     pub disk_write: History,
+    pub diskstats: DiskStats,
+    /// read requests completed between refreshes. (DOES NOT STORE RATE)
+    pub disk_read_ops: History,
+    /// write requests completed between refreshes. (DOES NOT STORE RATE)
+    pub disk_write_ops: History,
+    /// average request latency, in microseconds
+    pub disk_latency: History,
+    pub disk_in_flight: History,
+    /// milliseconds the busiest device was busy between refreshes
+    pub disk_busy_time: History,
+    pub drives: Vec<DriveData>,
+    /// temperature of the hottest drive, in degrees Celsius
+    pub disk_temperature: History<f32>,
     /// amount read between refresh of `sysinfo::Disks`. (DOES NOT STORE RATE)
     pub gpu_usage: Vec<History>,
     /// amount written between refresh of `sysinfo::Disks`. (DOES NOT STORE RATE)
     pub vram: Vec<History>,
+    pub load: LoadData,
+    /// 1-minute load average
+    pub load_avg: History<f32>,
+    /// runnable processes
+    pub procs_running: History,
+    /// processes blocked on I/O
+    pub procs_blocked: History,
+    pub psi: Pressures,
+    /// microseconds with some tasks stalled between refreshes, indexed by [`PsiResource::index`]
+    pub psi_some: [History; 3],
+    /// microseconds with all tasks stalled between refreshes, indexed by [`PsiResource::index`]
+    pub psi_full: [History; 3],
+    pub kernel: KernelActivity,
+    /// context switches between refreshes. (DOES NOT STORE RATE)
+    pub context_switches: History,
+    /// interrupts between refreshes. (DOES NOT STORE RATE)
+    pub interrupts: History,
+    /// processes and threads created between refreshes. (DOES NOT STORE RATE)
+    pub forks: History,
+    /// only refreshed when there is a `Processes` component
+    pub processes: Vec<ProcessData>,
+    /// cgroups of the `Cgroup` components, by their configured path
+    pub cgroups: HashMap<String, Cgroup>,
+    /// `None` when there is no wireless link
+    pub wifi: Option<WifiData>,
+    pub wifi_bitrates: BitrateReader,
+    /// link quality percentage
+    pub wifi_quality: History,
+    /// kbit/s
+    pub wifi_tx_bitrate: History,
+    /// kbit/s
+    pub wifi_rx_bitrate: History,
+    pub rapl: Rapl,
+    /// microjoules consumed between refreshes, indexed by [`PowerDomain::index`]. (DOES NOT STORE RATE)
+    pub power: [History; 4],
+    /// only refreshed when there is a `Fans` component
+    pub fans: Fans,
+    /// sources of the `Custom` components, without duplicates
+    pub customs: Vec<Custom>,
 }
 
 #[derive(Debug, Clone)]
@@ -57,6 +170,15 @@ pub enum Message {
     TickNet,
     TickDisk,
     TickGpu,
+    TickLoad,
+    TickPsi,
+    TickKernel,
+    TickProcesses,
+    TickCgroup,
+    TickWifi,
+    TickPower,
+    TickFans,
+    TickCustom,
     Surface(surface::Action),
 }
 
@@ -85,6 +207,7 @@ impl Application for SystemMonitorApplet {
 
     fn init(core: Core, flags: Self::Flags) -> (Self, Task<Self::Message>) {
         let (mut cpu, mut mem, mut net, mut disk, mut gpu) = Default::default();
+        let (mut load, mut psi, mut kernel, mut wifi, mut power, mut fans) = Default::default();
         let sampling = &flags.config.sampling;
         for chart_config in &flags.config.components {
             match chart_config {
@@ -93,23 +216,87 @@ impl Application for SystemMonitorApplet {
                 ComponentConfig::Net(_) => net = sampling.net.sampling_window,
                 ComponentConfig::Disk(_) => disk = sampling.disk.sampling_window,
                 ComponentConfig::Gpu(_) => gpu = sampling.gpu.sampling_window,
+                ComponentConfig::Load(_) => load = sampling.load.sampling_window,
+                ComponentConfig::Psi(_) => psi = sampling.psi.sampling_window,
+                ComponentConfig::Kernel(_) => kernel = sampling.kernel.sampling_window,
+                ComponentConfig::Wifi(_) => wifi = sampling.wifi.sampling_window,
+                ComponentConfig::Power(_) => power = sampling.power.sampling_window,
+                ComponentConfig::Fans(_) => fans = sampling.fans.sampling_window,
+                ComponentConfig::Processes(_)
+                | ComponentConfig::Cgroup { .. }
+                | ComponentConfig::Custom { .. } => {}
             }
         }
         let gpus = Gpus::new();
-        let app = Self {
+        let mut app = Self {
             core,
             config: flags.config,
             config_handler: flags.config_handler,
 
             global_cpu: History::with_capacity(cpu),
+            cpu_times: CpuTimes::default(),
+            cpu_user: History::with_capacity(cpu),
+            cpu_system: History::with_capacity(cpu),
+            cpu_iowait: History::with_capacity(cpu),
             ram: History::with_capacity(mem),
             swap: History::with_capacity(mem),
+            meminfo: MemInfo::default(),
+            ram_available: History::with_capacity(mem),
+            ram_breakdown: std::array::from_fn(|_| History::with_capacity(mem)),
+            compressed_swap: CompressedSwap::default(),
+            swap_original: History::with_capacity(mem),
+            swap_compressed: History::with_capacity(mem),
+            vmstat: VmStat::default(),
+            swap_in: History::with_capacity(mem),
+            swap_out: History::with_capacity(mem),
+            major_faults: History::with_capacity(mem),
+            oom_kills: History::with_capacity(mem),
             upload: History::with_capacity(net),
             download: History::with_capacity(net),
+            interfaces: InterfaceStats::default(),
+            packets_received: History::with_capacity(net),
+            packets_transmitted: History::with_capacity(net),
+            packet_errors: History::with_capacity(net),
+            packet_drops: History::with_capacity(net),
+            sockets: SocketStats::default(),
+            tcp_established: History::with_capacity(net),
+            tcp_listening: History::with_capacity(net),
+            tcp_retransmits: History::with_capacity(net),
+            udp_errors: History::with_capacity(net),
             disk_read: History::with_capacity(disk),
             disk_write: History::with_capacity(disk),
+            diskstats: DiskStats::default(),
+            disk_read_ops: History::with_capacity(disk),
+            disk_write_ops: History::with_capacity(disk),
+            disk_latency: History::with_capacity(disk),
+            disk_in_flight: History::with_capacity(disk),
+            disk_busy_time: History::with_capacity(disk),
+            drives: Vec::new(),
+            disk_temperature: History::with_capacity(disk),
             gpu_usage: vec![History::with_capacity(gpu); gpus.num_gpus()],
             vram: vec![History::with_capacity(gpu); gpus.num_gpus()],
+            load: LoadData::default(),
+            load_avg: History::with_capacity(load),
+            procs_running: History::with_capacity(load),
+            procs_blocked: History::with_capacity(load),
+            psi: Pressures::default(),
+            psi_some: std::array::from_fn(|_| History::with_capacity(psi)),
+            psi_full: std::array::from_fn(|_| History::with_capacity(psi)),
+            kernel: KernelActivity::default(),
+            context_switches: History::with_capacity(kernel),
+            interrupts: History::with_capacity(kernel),
+            forks: History::with_capacity(kernel),
+            processes: Vec::new(),
+            cgroups: HashMap::new(),
+            wifi: None,
+            wifi_bitrates: BitrateReader::default(),
+            wifi_quality: History::with_capacity(wifi),
+            wifi_tx_bitrate: History::with_capacity(wifi),
+            wifi_rx_bitrate: History::with_capacity(wifi),
+            rapl: Rapl::default(),
+            power: std::array::from_fn(|_| History::with_capacity(power)),
+            fans: Fans::new(fans),
+            customs: Vec::new(),
 
             sys: System::new_with_specifics(
                 RefreshKind::nothing()
@@ -122,6 +309,8 @@ impl Application for SystemMonitorApplet {
             ),
             gpus,
         };
+        app.sync_cgroups();
+        app.sync_customs();
 
         (app, Task::none())
     }
@@ -134,6 +323,20 @@ impl Application for SystemMonitorApplet {
                 ComponentConfig::Net(vis) => self.net_view(vis),
                 ComponentConfig::Disk(vis) => self.disk_view(vis),
                 ComponentConfig::Gpu(vis) => self.gpu_view(vis),
+                ComponentConfig::Load(vis) => self.load_view(vis),
+                ComponentConfig::Psi(vis) => self.psi_view(vis),
+                ComponentConfig::Kernel(vis) => self.kernel_view(vis),
+                ComponentConfig::Processes(vis) => self.processes_view(vis),
+                ComponentConfig::Cgroup { path, views } => self.cgroup_view(path, views),
+                ComponentConfig::Wifi(vis) => self.wifi_view(vis),
+                ComponentConfig::Power(vis) => self.power_view(vis),
+                ComponentConfig::Fans(vis) => self.fans_view(vis),
+                ComponentConfig::Custom {
+                    source,
+                    unit,
+                    max,
+                    views,
+                } => self.custom_view(source, unit, *max, views),
             }
             .apply(|elements| {
                 self.panel_collection(elements, self.config.layout.inner_spacing, 0.0)
@@ -151,20 +354,71 @@ impl Application for SystemMonitorApplet {
                 self.config = config;
                 let sampling = &self.config.sampling;
                 self.global_cpu.resize(sampling.cpu.sampling_window);
+                self.cpu_user.resize(sampling.cpu.sampling_window);
+                self.cpu_system.resize(sampling.cpu.sampling_window);
+                self.cpu_iowait.resize(sampling.cpu.sampling_window);
                 self.ram.resize(sampling.mem.sampling_window);
                 self.swap.resize(sampling.mem.sampling_window);
+                self.ram_available.resize(sampling.mem.sampling_window);
+                for history in &mut self.ram_breakdown {
+                    history.resize(sampling.mem.sampling_window);
+                }
+                self.swap_original.resize(sampling.mem.sampling_window);
+                self.swap_compressed.resize(sampling.mem.sampling_window);
+                self.swap_in.resize(sampling.mem.sampling_window);
+                self.swap_out.resize(sampling.mem.sampling_window);
+                self.major_faults.resize(sampling.mem.sampling_window);
+                self.oom_kills.resize(sampling.mem.sampling_window);
                 self.upload.resize(sampling.net.sampling_window);
                 self.download.resize(sampling.net.sampling_window);
+                self.packets_received.resize(sampling.net.sampling_window);
+                self.packets_transmitted
+                    .resize(sampling.net.sampling_window);
+                self.packet_errors.resize(sampling.net.sampling_window);
+                self.packet_drops.resize(sampling.net.sampling_window);
+                self.tcp_established.resize(sampling.net.sampling_window);
+                self.tcp_listening.resize(sampling.net.sampling_window);
+                self.tcp_retransmits.resize(sampling.net.sampling_window);
+                self.udp_errors.resize(sampling.net.sampling_window);
                 self.disk_read.resize(sampling.disk.sampling_window);
                 self.disk_write.resize(sampling.disk.sampling_window);
+                self.disk_read_ops.resize(sampling.disk.sampling_window);
+                self.disk_write_ops.resize(sampling.disk.sampling_window);
+                self.disk_latency.resize(sampling.disk.sampling_window);
+                self.disk_in_flight.resize(sampling.disk.sampling_window);
+                self.disk_busy_time.resize(sampling.disk.sampling_window);
+                self.disk_temperature.resize(sampling.disk.sampling_window);
                 for i in 0..self.gpus.num_gpus() {
                     self.gpu_usage[i].resize(sampling.gpu.sampling_window);
                     self.vram[i].resize(sampling.gpu.sampling_window);
                 }
+                self.load_avg.resize(sampling.load.sampling_window);
+                self.procs_running.resize(sampling.load.sampling_window);
+                self.procs_blocked.resize(sampling.load.sampling_window);
+                for history in self.psi_some.iter_mut().chain(&mut self.psi_full) {
+                    history.resize(sampling.psi.sampling_window);
+                }
+                self.context_switches
+                    .resize(sampling.kernel.sampling_window);
+                self.interrupts.resize(sampling.kernel.sampling_window);
+                self.forks.resize(sampling.kernel.sampling_window);
+                self.wifi_quality.resize(sampling.wifi.sampling_window);
+                self.wifi_tx_bitrate.resize(sampling.wifi.sampling_window);
+                self.wifi_rx_bitrate.resize(sampling.wifi.sampling_window);
+                for history in &mut self.power {
+                    history.resize(sampling.power.sampling_window);
+                }
+                self.fans.resize(sampling.fans.sampling_window);
+                self.sync_cgroups();
+                self.sync_customs();
             }
             Message::TickCpu => {
                 self.sys.refresh_cpu_usage();
                 self.global_cpu.push(self.sys.global_cpu_usage());
+                self.cpu_times.refresh();
+                self.cpu_user.push(self.cpu_times.data.user);
+                self.cpu_system.push(self.cpu_times.data.system);
+                self.cpu_iowait.push(self.cpu_times.data.iowait);
             }
             Message::Surface(a) => {
                 return cosmic::task::message(cosmic::Action::Cosmic(
@@ -175,6 +429,23 @@ impl Application for SystemMonitorApplet {
                 self.sys.refresh_memory();
                 self.ram.push(self.sys.used_memory());
                 self.swap.push(self.sys.used_swap());
+                let meminfo = read_table("/proc/meminfo");
+                self.meminfo = MemInfo::from_table(&meminfo);
+                self.ram_available.push(self.meminfo.available);
+                let info = &self.meminfo;
+                let breakdown = [info.used, info.shared, info.buffers, info.cache, info.dirty];
+                for (history, value) in self.ram_breakdown.iter_mut().zip(breakdown) {
+                    history.push(value);
+                }
+                self.compressed_swap = CompressedSwap::read(&meminfo);
+                let compressed = self.compressed_swap.total();
+                self.swap_original.push(compressed.original);
+                self.swap_compressed.push(compressed.compressed);
+                self.vmstat.refresh();
+                self.swap_in.push(self.vmstat.data.swap_in);
+                self.swap_out.push(self.vmstat.data.swap_out);
+                self.major_faults.push(self.vmstat.data.major_faults);
+                self.oom_kills.push(self.vmstat.data.oom_kills);
             }
             Message::TickNet => {
                 self.nets.refresh(true);
@@ -184,6 +455,17 @@ impl Application for SystemMonitorApplet {
                     });
                 self.upload.push(transmitted);
                 self.download.push(received);
+                self.interfaces.refresh(&self.nets);
+                let packets = self.interfaces.total();
+                self.packets_received.push(packets.received);
+                self.packets_transmitted.push(packets.transmitted);
+                self.packet_errors.push(packets.errors);
+                self.packet_drops.push(packets.drops);
+                self.sockets.refresh();
+                self.tcp_established.push(self.sockets.data.established);
+                self.tcp_listening.push(self.sockets.data.listening);
+                self.tcp_retransmits.push(self.sockets.data.retransmits);
+                self.udp_errors.push(self.sockets.data.udp_errors);
             }
             Message::TickDisk => {
                 self.disks
@@ -197,6 +479,24 @@ impl Application for SystemMonitorApplet {
                     });
                 self.disk_read.push(read);
                 self.disk_write.push(written);
+                self.diskstats.refresh();
+                let total = self.diskstats.total();
+                self.disk_read_ops.push(total.reads);
+                self.disk_write_ops.push(total.writes);
+                self.disk_latency.push(total.latency());
+                self.disk_in_flight.push(total.in_flight);
+                self.disk_busy_time.push(
+                    self.diskstats
+                        .busiest()
+                        .map_or(0, |(_, data)| data.busy_time),
+                );
+                self.drives = DriveData::read_all();
+                self.disk_temperature.push(
+                    self.drives
+                        .iter()
+                        .map(|drive| drive.temperature)
+                        .fold(0.0, f32::max),
+                );
             }
             Message::TickGpu => {
                 self.gpus.refresh();
@@ -205,6 +505,58 @@ impl Application for SystemMonitorApplet {
                     self.vram[idx].push(data.used_vram);
                 }
             }
+            Message::TickLoad => {
+                self.load = LoadData::read();
+                self.load_avg.push(self.load.one);
+                self.procs_running.push(self.load.running);
+                self.procs_blocked.push(self.load.blocked);
+            }
+            Message::TickPsi => {
+                self.psi.refresh();
+                for resource in PsiResource::ALL {
+                    let data = self.psi.data(resource);
+                    self.psi_some[resource.index()].push(data.some_stalled);
+                    self.psi_full[resource.index()].push(data.full_stalled);
+                }
+            }
+            Message::TickKernel => {
+                self.kernel.refresh();
+                self.context_switches
+                    .push(self.kernel.data.context_switches);
+                self.interrupts.push(self.kernel.data.interrupts);
+                self.forks.push(self.kernel.data.forks);
+            }
+            Message::TickProcesses => {
+                self.processes = ProcessData::read_all(&mut self.sys);
+            }
+            Message::TickCgroup => {
+                for cgroup in self.cgroups.values_mut() {
+                    cgroup.refresh();
+                }
+            }
+            Message::TickWifi => {
+                self.wifi = WifiData::read(&mut self.wifi_bitrates);
+                let (quality, tx_bitrate, rx_bitrate) =
+                    self.wifi.as_ref().map_or((0, 0, 0), |wifi| {
+                        (wifi.quality, wifi.tx_bitrate, wifi.rx_bitrate)
+                    });
+                self.wifi_quality.push(quality);
+                self.wifi_tx_bitrate.push(tx_bitrate);
+                self.wifi_rx_bitrate.push(rx_bitrate);
+            }
+            Message::TickPower => {
+                self.rapl.refresh();
+                for domain in PowerDomain::ALL {
+                    self.power[domain.index()].push(self.rapl.energy(domain));
+                }
+            }
+            Message::TickFans => self.fans.refresh(),
+            Message::TickCustom => {
+                let update_interval = self.config.sampling.custom.update_interval;
+                for custom in &mut self.customs {
+                    custom.refresh(update_interval);
+                }
+            }
         }
         Task::none()
     }
@@ -235,6 +587,42 @@ impl Application for SystemMonitorApplet {
                         sampling.gpu.update_interval,
                     ))
                     .map(|_| Message::TickGpu),
+                    ComponentConfig::Load(_) => cosmic::iced::time::every(Duration::from_millis(
+                        sampling.load.update_interval,
+                    ))
+                    .map(|_| Message::TickLoad),
+                    ComponentConfig::Psi(_) => cosmic::iced::time::every(Duration::from_millis(
+                        sampling.psi.update_interval,
+                    ))
+                    .map(|_| Message::TickPsi),
+                    ComponentConfig::Kernel(_) => cosmic::iced::time::every(Duration::from_millis(
+                        sampling.kernel.update_interval,
+                    ))
+                    .map(|_| Message::TickKernel),
+                    ComponentConfig::Processes(_) => cosmic::iced::time::every(
+                        Duration::from_millis(sampling.processes.update_interval),
+                    )
+                    .map(|_| Message::TickProcesses),
+                    ComponentConfig::Cgroup { .. } => cosmic::iced::time::every(
+                        Duration::from_millis(sampling.cgroup.update_interval),
+                    )
+                    .map(|_| Message::TickCgroup),
+                    ComponentConfig::Wifi(_) => cosmic::iced::time::every(Duration::from_millis(
+                        sampling.wifi.update_interval,
+                    ))
+                    .map(|_| Message::TickWifi),
+                    ComponentConfig::Power(_) => cosmic::iced::time::every(Duration::from_millis(
+                        sampling.power.update_interval,
+                    ))
+                    .map(|_| Message::TickPower),
+                    ComponentConfig::Fans(_) => cosmic::iced::time::every(Duration::from_millis(
+                        sampling.fans.update_interval,
+                    ))
+                    .map(|_| Message::TickFans),
+                    ComponentConfig::Custom { .. } => cosmic::iced::time::every(
+                        Duration::from_millis(sampling.custom.update_interval),
+                    )
+                    .map(|_| Message::TickCustom),
                 }
             };
             subs.push(tick);
@@ -250,6 +638,48 @@ impl Application for SystemMonitorApplet {
     }
 }
 
+impl SystemMonitorApplet {
+    /// Keeps a [`Cgroup`] for each configured path, preserving the history of the existing ones
+    fn sync_cgroups(&mut self) {
+        let sampling_window = self.config.sampling.cgroup.sampling_window;
+        let mut cgroups = HashMap::new();
+        for component in &self.config.components {
+            if let ComponentConfig::Cgroup { path, .. } = component {
+                if cgroups.contains_key(path) {
+                    continue;
+                }
+                let mut cgroup = self
+                    .cgroups
+                    .remove(path)
+                    .unwrap_or_else(|| Cgroup::new(path, sampling_window));
+                cgroup.resize(sampling_window);
+                cgroups.insert(path.clone(), cgroup);
+            }
+        }
+        self.cgroups = cgroups;
+    }
+
+    /// Keeps a [`Custom`] for each configured source, preserving the history of the existing ones
+    fn sync_customs(&mut self) {
+        let sampling_window = self.config.sampling.custom.sampling_window;
+        let mut customs: Vec<Custom> = Vec::new();
+        for component in &self.config.components {
+            if let ComponentConfig::Custom { source, .. } = component {
+                if customs.iter().any(|custom| custom.source == *source) {
+                    continue;
+                }
+                let mut custom = match self.customs.iter().position(|c| c.source == *source) {
+                    Some(index) => self.customs.swap_remove(index),
+                    None => Custom::new(source.clone(), sampling_window),
+                };
+                custom.resize(sampling_window);
+                customs.push(custom);
+            }
+        }
+        self.customs = customs;
+    }
+}
+
 pub fn base_background(theme: &Theme) -> container::Style {
     container::Style {
         background: Some(cosmic::iced::Color::from(theme.cosmic().primary.base).into()),
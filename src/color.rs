@@ -89,3 +89,94 @@ impl Color {
         }
     }
 }
+
+/// Colors replacing the color of a view once its value reaches a percentage of its scale
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Thresholds {
+    pub warning: Option<Threshold>,
+    pub critical: Option<Threshold>,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Threshold {
+    pub percentage: f32,
+    pub color: Color,
+}
+
+impl Thresholds {
+    /// The warning then the critical threshold, when set
+    pub fn levels(&self) -> impl Iterator<Item = Threshold> {
+        self.warning.into_iter().chain(self.critical)
+    }
+
+    /// Color of the highest threshold reached by `percentage`, if any
+    pub fn reached(&self, percentage: f32) -> Option<Color> {
+        self.levels()
+            .filter(|threshold| percentage >= threshold.percentage)
+            .max_by(|a, b| a.percentage.total_cmp(&b.percentage))
+            .map(|threshold| threshold.color)
+    }
+
+    /// `color`, or the color of the highest threshold reached by `percentage`
+    pub fn color(&self, percentage: f32, color: Color) -> Color {
+        self.reached(percentage).unwrap_or(color)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn color_pair(&self, current: u64, max: u64, color: Color) -> Color {
+        if max == 0 {
+            return color;
+        }
+        self.color(current as f32 / max as f32 * 100.0, color)
+    }
+}
+
+/// How run charts and bars are filled
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum FillStyle {
+    /// The color of the line, lighter
+    #[default]
+    Solid,
+    /// The color of the line, fading out towards the bottom of run charts and the base of bars
+    Gradient,
+    /// Colors spread evenly from the bottom to the top of the scale, replacing the color of the view,
+    /// e.g. `Ramp([accent_green, accent_yellow, accent_red])`
+    Ramp(Box<[Color]>),
+}
+
+impl FillStyle {
+    /// Color of the ramp at `percentage` of the scale, blending its two closest colors,
+    /// or `color` when there is no ramp. An unknown percentage (NaN) gets the first color.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn color_at(&self, percentage: f32, color: &Color, theme: &Theme) -> CosmicColor {
+        let stops = match self {
+            FillStyle::Ramp(stops) if !stops.is_empty() => stops,
+            _ => return color.as_cosmic_color(theme),
+        };
+        if stops.len() == 1 || !percentage.is_finite() {
+            return stops[0].as_cosmic_color(theme);
+        }
+        let position = percentage.clamp(0.0, 100.0) / 100.0 * (stops.len() - 1) as f32;
+        let index = (position.floor() as usize).min(stops.len() - 2);
+        mix(
+            stops[index].as_cosmic_color(theme),
+            stops[index + 1].as_cosmic_color(theme),
+            position - index as f32,
+        )
+    }
+}
+
+fn mix(from: CosmicColor, to: CosmicColor, amount: f32) -> CosmicColor {
+    let blend = |from: f32, to: f32| from + (to - from) * amount;
+    CosmicColor::new(
+        blend(from.red, to.red),
+        blend(from.green, to.green),
+        blend(from.blue, to.blue),
+        blend(from.alpha, to.alpha),
+    )
+}
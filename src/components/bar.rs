@@ -1,13 +1,19 @@
 use std::cmp::Ordering;
+use std::f32::consts::PI;
 
-use crate::{applet::Message, color::Color};
+use crate::{
+    applet::Message,
+    color::{Color, FillStyle, Thresholds},
+};
 use cosmic::{
     Element, Renderer, Theme,
     cosmic_theme::palette::WithAlpha,
     iced::{
-        self,
+        self, Background,
         Length::Fill,
+        Radians,
         core::{Layout, Length, Rectangle, Size, layout, mouse, renderer, widget::Tree},
+        gradient::Linear,
     },
     widget::Widget,
 };
@@ -46,13 +52,65 @@ impl PercentageBar {
         }
     }
 
-    #[allow(clippy::cast_precision_loss)]
     pub(crate) fn from_pair(is_horizontal: bool, current: u64, max: u64, color: Color) -> Self {
-        let value = current as f32 / max as f32 * 100.0;
-        Self::new(is_horizontal, value, color)
+        Self::new(is_horizontal, percentage(current, max), color)
+    }
+
+    pub(crate) fn fill(mut self, fill: FillStyle) -> Self {
+        match &mut self {
+            Self::Vertical(bar) => bar.fill = fill,
+            Self::Horizontal(bar) => bar.fill = fill,
+        }
+        self
+    }
+
+    pub(crate) fn thresholds(mut self, thresholds: Thresholds) -> Self {
+        match &mut self {
+            Self::Vertical(bar) => bar.thresholds = thresholds,
+            Self::Horizontal(bar) => bar.thresholds = thresholds,
+        }
+        self
+    }
+}
+
+/// Share of `max` that `current` is, with an empty bar when `max` is 0
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn percentage(current: u64, max: u64) -> f32 {
+    if max == 0 {
+        0.0
+    } else {
+        current as f32 / max as f32 * 100.0
     }
 }
 
+/// Colors of the edge line and of the fill of a bar, a gradient fading out opposite to `towards_edge`
+fn paint(
+    percentage: f32,
+    color: &Color,
+    fill: &FillStyle,
+    thresholds: &Thresholds,
+    theme: &Theme,
+    towards_edge: Radians,
+) -> (iced::Color, Background) {
+    let line_color = match thresholds.reached(percentage) {
+        Some(color) => color.as_cosmic_color(theme),
+        None => fill.color_at(percentage, color, theme),
+    };
+    // make the fill more transparent
+    let fill_color = iced::Color::from(line_color.with_alpha(line_color.alpha / 2.0));
+    let background = if *fill == FillStyle::Gradient {
+        Background::Gradient(
+            Linear::new(towards_edge)
+                .add_stop(0.0, iced::Color::TRANSPARENT)
+                .add_stop(1.0, fill_color)
+                .into(),
+        )
+    } else {
+        Background::Color(fill_color)
+    };
+    (iced::Color::from(line_color), background)
+}
+
 impl From<PercentageBar> for Element<'_, Message> {
     fn from(value: PercentageBar) -> Self {
         Element::new(value)
@@ -108,6 +166,8 @@ impl Widget<Message, Theme, Renderer> for PercentageBar {
 pub struct VerticalPercentageBar {
     percentage: f32,
     color: Color,
+    fill: FillStyle,
+    thresholds: Thresholds,
 }
 
 impl VerticalPercentageBar {
@@ -115,13 +175,13 @@ impl VerticalPercentageBar {
         VerticalPercentageBar {
             percentage: value.clamp(0.0, 100.0),
             color,
+            fill: FillStyle::default(),
+            thresholds: Thresholds::default(),
         }
     }
 
-    #[allow(clippy::cast_precision_loss)]
     pub fn from_pair(current: u64, max: u64, color: Color) -> Self {
-        let value = current as f32 / max as f32 * 100.0;
-        Self::new(value, color)
+        Self::new(percentage(current, max), color)
     }
 }
 
@@ -162,9 +222,16 @@ impl Widget<Message, Theme, Renderer> for VerticalPercentageBar {
         };
 
         let edge_line_thickness = 0.01 * outer_rect.height;
+        let (line_color, fill) = paint(
+            self.percentage,
+            &self.color,
+            &self.fill,
+            &self.thresholds,
+            theme,
+            Radians(0.0),
+        );
 
         // line
-        let line_color = self.color.as_cosmic_color(theme);
         iced::core::Renderer::fill_quad(
             renderer,
             renderer::Quad {
@@ -175,7 +242,7 @@ impl Widget<Message, Theme, Renderer> for VerticalPercentageBar {
                 },
                 ..renderer::Quad::default()
             },
-            iced::Color::from(line_color),
+            line_color,
         );
 
         // fill below line
@@ -185,8 +252,7 @@ impl Widget<Message, Theme, Renderer> for VerticalPercentageBar {
                 bounds: fill_rect,
                 ..renderer::Quad::default()
             },
-            // make the fill more transparent
-            iced::Color::from(line_color.with_alpha(line_color.alpha / 2.0)),
+            fill,
         );
     }
 }
@@ -200,12 +266,16 @@ impl<'a> From<VerticalPercentageBar> for Element<'a, Message> {
 pub struct HorizontalPercentageBar {
     percentage: f32,
     color: Color,
+    fill: FillStyle,
+    thresholds: Thresholds,
 }
 impl HorizontalPercentageBar {
     pub fn new(value: f32, color: Color) -> Self {
         Self {
             percentage: value.clamp(0.0, 100.0),
             color,
+            fill: FillStyle::default(),
+            thresholds: Thresholds::default(),
         }
     }
 }
@@ -248,8 +318,16 @@ impl Widget<Message, Theme, Renderer> for HorizontalPercentageBar {
         };
 
         let edge_line_thickness = 0.01 * outer_rect.height;
+        // The bar grows from the right, so the gradient goes to the left
+        let (line_color, fill) = paint(
+            self.percentage,
+            &self.color,
+            &self.fill,
+            &self.thresholds,
+            theme,
+            Radians(3.0 * PI / 2.0),
+        );
 
-        let line_color = self.color.as_cosmic_color(theme);
         iced::core::Renderer::fill_quad(
             renderer,
             renderer::Quad {
@@ -260,7 +338,7 @@ impl Widget<Message, Theme, Renderer> for HorizontalPercentageBar {
                 },
                 ..renderer::Quad::default()
             },
-            iced::Color::from(line_color),
+            line_color,
         );
         iced::core::Renderer::fill_quad(
             renderer,
@@ -268,8 +346,7 @@ impl Widget<Message, Theme, Renderer> for HorizontalPercentageBar {
                 bounds: fill_rect,
                 ..renderer::Quad::default()
             },
-            // make the fill more transparent
-            iced::Color::from(line_color.with_alpha(line_color.alpha / 2.0)),
+            fill,
         );
     }
 }
@@ -279,3 +356,138 @@ impl From<HorizontalPercentageBar> for Element<'_, Message> {
         Element::new(value)
     }
 }
+
+/// Bar split into consecutive segments, each a percentage of the whole bar
+pub struct StackedBar {
+    is_horizontal: bool,
+    segments: Vec<(f32, Color)>,
+    /// line drawn across the bar at some percentage
+    marker: Option<(f32, Color)>,
+}
+
+impl StackedBar {
+    pub(crate) fn from_pairs(
+        is_horizontal: bool,
+        segments: impl IntoIterator<Item = (u64, Color)>,
+        max: u64,
+    ) -> Self {
+        let segments = segments
+            .into_iter()
+            .map(|(current, color)| (percentage(current, max), color))
+            .collect();
+        Self {
+            is_horizontal,
+            segments,
+            marker: None,
+        }
+    }
+
+    pub(crate) fn with_marker(mut self, current: u64, max: u64, color: Color) -> Self {
+        self.marker = Some((percentage(current, max), color));
+        self
+    }
+
+    /// The section of the bar between `start` and `start + length` percent, growing like [`PercentageBar`]
+    fn section(&self, bounds: &Rectangle, start: f32, length: f32) -> Rectangle {
+        if self.is_horizontal {
+            let height = length / 100.0 * bounds.height;
+            Rectangle {
+                y: bounds.y + bounds.height - start / 100.0 * bounds.height - height,
+                height,
+                ..*bounds
+            }
+        } else {
+            let width = length / 100.0 * bounds.width;
+            Rectangle {
+                x: bounds.x + bounds.width - start / 100.0 * bounds.width - width,
+                width,
+                ..*bounds
+            }
+        }
+    }
+}
+
+impl Widget<Message, Theme, Renderer> for StackedBar {
+    fn size(&self) -> Size<Length> {
+        Size::new(Fill, Fill)
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Size { width, height } = Widget::<Message, Theme, Renderer>::size(self);
+        layout::atomic(limits, width, height)
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let outer_rect = &layout.bounds();
+        let edge_line_thickness = 0.01 * outer_rect.height;
+
+        let mut start = 0.0;
+        for (percentage, color) in &self.segments {
+            let length = percentage.clamp(0.0, 100.0 - start);
+            if length <= 0.0 {
+                continue;
+            }
+            let fill_rect = self.section(outer_rect, start, length);
+            let line_rect = self.section(outer_rect, start + length, 0.0);
+            let line_color = color.as_cosmic_color(theme);
+            iced::core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: fill_rect,
+                    ..renderer::Quad::default()
+                },
+                // make the fill more transparent
+                iced::Color::from(line_color.with_alpha(line_color.alpha / 2.0)),
+            );
+            iced::core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: line_rect.width.max(edge_line_thickness),
+                        height: line_rect.height.max(edge_line_thickness),
+                        ..line_rect
+                    },
+                    ..renderer::Quad::default()
+                },
+                iced::Color::from(line_color),
+            );
+            start += length;
+        }
+
+        if let Some((percentage, color)) = self.marker {
+            let line_rect = self.section(outer_rect, percentage.clamp(0.0, 100.0), 0.0);
+            iced::core::Renderer::fill_quad(
+                renderer,
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: line_rect.width.max(2.0 * edge_line_thickness),
+                        height: line_rect.height.max(2.0 * edge_line_thickness),
+                        ..line_rect
+                    },
+                    ..renderer::Quad::default()
+                },
+                iced::Color::from(color.as_cosmic_color(theme)),
+            );
+        }
+    }
+}
+
+impl From<StackedBar> for Element<'_, Message> {
+    fn from(value: StackedBar) -> Self {
+        Element::new(value)
+    }
+}
@@ -0,0 +1,100 @@
+use std::path::{Path, PathBuf};
+
+use super::{
+    gpu::read_syspath,
+    procfs::{Counter, read_table},
+};
+use crate::history::History;
+
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// Usage of a cgroup between refreshes
+#[derive(Clone, Copy, Default)]
+pub struct CgroupData {
+    /// CPU time used, in microseconds
+    pub cpu_time: u64,
+    pub memory: u64,
+    /// `None` when the cgroup memory isn't limited
+    pub memory_max: Option<u64>,
+    pub io_read: u64,
+    pub io_write: u64,
+}
+
+/// A cgroup v2 and the history of its usage
+pub struct Cgroup {
+    path: PathBuf,
+    cpu_time: Counter,
+    io_read: Counter,
+    io_write: Counter,
+    pub data: CgroupData,
+    /// CPU time used between refreshes. (DOES NOT STORE RATE)
+    pub cpu_history: History,
+    pub memory_history: History,
+    /// amount read between refreshes. (DOES NOT STORE RATE)
+    pub read_history: History,
+    /// amount written between refreshes. (DOES NOT STORE RATE)
+    pub write_history: History,
+}
+
+impl Cgroup {
+    /// `path` is relative to `/sys/fs/cgroup`, e.g. `user.slice`, but may also start with it
+    pub fn new(path: &str, sampling_window: usize) -> Self {
+        let relative = path.trim_start_matches(CGROUP_ROOT).trim_start_matches('/');
+        Self {
+            path: Path::new(CGROUP_ROOT).join(relative),
+            cpu_time: Counter::default(),
+            io_read: Counter::default(),
+            io_write: Counter::default(),
+            data: CgroupData::default(),
+            cpu_history: History::with_capacity(sampling_window),
+            memory_history: History::with_capacity(sampling_window),
+            read_history: History::with_capacity(sampling_window),
+            write_history: History::with_capacity(sampling_window),
+        }
+    }
+
+    pub fn resize(&mut self, sampling_window: usize) {
+        self.cpu_history.resize(sampling_window);
+        self.memory_history.resize(sampling_window);
+        self.read_history.resize(sampling_window);
+        self.write_history.resize(sampling_window);
+    }
+
+    pub fn refresh(&mut self) {
+        let cpu_stat = read_table(self.path.join("cpu.stat"));
+        let (read, write) = read_io_stat(&self.path.join("io.stat"));
+        self.data = CgroupData {
+            cpu_time: self
+                .cpu_time
+                .delta(cpu_stat.get("usage_usec").copied().unwrap_or_default()),
+            memory: read_syspath(&self.path, "memory.current").unwrap_or_default(),
+            // The limit is the literal `max` when there is none, which doesn't parse
+            memory_max: read_syspath(&self.path, "memory.max"),
+            io_read: self.io_read.delta(read),
+            io_write: self.io_write.delta(write),
+        };
+
+        self.cpu_history.push(self.data.cpu_time);
+        self.memory_history.push(self.data.memory);
+        self.read_history.push(self.data.io_read);
+        self.write_history.push(self.data.io_write);
+    }
+}
+
+/// Sums `rbytes` and `wbytes` of all devices, in lines like `259:0 rbytes=0 wbytes=0 rios=0 wios=0`
+fn read_io_stat(path: &Path) -> (u64, u64) {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return (0, 0);
+    };
+    content
+        .split_whitespace()
+        .filter_map(|field| field.split_once('='))
+        .fold((0, 0), |(read, write), (key, value)| {
+            let value = value.parse().unwrap_or(0);
+            match key {
+                "rbytes" => (read + value, write),
+                "wbytes" => (read, write + value),
+                _ => (read, write),
+            }
+        })
+}
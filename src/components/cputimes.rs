@@ -0,0 +1,50 @@
+use super::procfs::Counter;
+
+/// CPU time spent in each mode by all cores between refreshes, in clock ticks
+#[derive(Clone, Copy, Default)]
+pub struct CpuTimesData {
+    /// user and niced processes
+    pub user: u64,
+    /// kernel, including interrupts
+    pub system: u64,
+    /// idle while waiting for I/O
+    pub iowait: u64,
+    /// all modes, including idle
+    pub total: u64,
+}
+
+#[derive(Default)]
+pub struct CpuTimes {
+    user: Counter,
+    system: Counter,
+    iowait: Counter,
+    total: Counter,
+    pub data: CpuTimesData,
+}
+
+impl CpuTimes {
+    /// Reads the `cpu  user nice system idle iowait irq softirq steal ...` line of `/proc/stat`
+    pub fn refresh(&mut self) {
+        let Some(times) = std::fs::read_to_string("/proc/stat")
+            .ok()
+            .and_then(|content| {
+                let line = content.lines().find(|line| line.starts_with("cpu "))?;
+                line.split_whitespace()
+                    .skip(1)
+                    .take(8)
+                    .map(|time| time.parse::<u64>().ok())
+                    .collect::<Option<Vec<_>>>()
+            })
+            .filter(|times| times.len() == 8)
+        else {
+            return;
+        };
+        // Guest time is already counted in user time, so it's left out of the total
+        self.data = CpuTimesData {
+            user: self.user.delta(times[0] + times[1]),
+            system: self.system.delta(times[2] + times[5] + times[6]),
+            iowait: self.iowait.delta(times[4]),
+            total: self.total.delta(times.iter().sum()),
+        };
+    }
+}
@@ -0,0 +1,499 @@
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, ErrorKind};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicBool, Ordering},
+};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::gpu::read_syspath;
+use crate::history::History;
+
+/// Where the values of a `Custom` component come from
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum CustomSource {
+    /// Shell command run with `sh -c` every update, printing its values to stdout
+    Command(String),
+    /// Path of a named pipe, read line by line as values are written to it
+    Fifo(String),
+    /// File holding a number, such as a sysfs or procfs attribute, read every update
+    File {
+        path: String,
+        /// Prefix of the line holding the number, e.g. `MemAvailable:`, for files with one value per line
+        #[serde(default)]
+        line: Option<String>,
+        /// Index of the whitespace-separated field holding the number, in the file or after the `line` prefix
+        #[serde(default)]
+        field: Option<usize>,
+        /// Pattern matching the number, or its first capture group, in the file or what `line` and `field` selected
+        #[serde(default)]
+        regex: Option<String>,
+        /// Factor applied to the value, e.g. `0.001` for millidegrees
+        #[serde(default = "default_scale")]
+        scale: f32,
+        #[serde(default)]
+        mode: FileMode,
+    },
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+/// How the number of a `File` source is charted
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub enum FileMode {
+    /// The number itself, e.g. a temperature
+    #[default]
+    Gauge,
+    /// How much the number grew per second, for counters such as `energy_uj`
+    Rate,
+}
+
+/// Values read from a FIFO, shared with the thread reading it
+type SharedValues = Arc<Mutex<Vec<(String, f32)>>>;
+
+/// A `Custom` component source and the history of its values
+pub struct Custom {
+    pub source: CustomSource,
+    /// command started on the previous update and the thread reading its stdout,
+    /// whose output is collected on the next one
+    child: Option<(Child, JoinHandle<String>)>,
+    /// latest values written to the FIFO, shared with the thread reading it
+    fifo_values: Option<SharedValues>,
+    /// tells the thread reading the FIFO to stop
+    fifo_stop: Arc<AtomicBool>,
+    /// compiled `regex` of a `File` source, or why it doesn't compile
+    regex: Option<Result<Regex, String>>,
+    /// previous number of a `File` source, for the `Rate` mode
+    last: Option<f64>,
+    /// latest values, in the order they were printed
+    pub values: Vec<(String, f32)>,
+    /// why the latest read of a `File` source failed
+    pub error: Option<String>,
+    histories: HashMap<String, History<f32>>,
+    /// charted until a value is read, so that the views and their tooltips show up
+    empty: History<f32>,
+    sampling_window: usize,
+}
+
+impl Custom {
+    pub fn new(source: CustomSource, sampling_window: usize) -> Self {
+        let fifo_stop = Arc::new(AtomicBool::new(false));
+        let fifo_values = match &source {
+            CustomSource::Fifo(path) => {
+                Some(spawn_fifo_reader(path.clone(), Arc::clone(&fifo_stop)))
+            }
+            CustomSource::Command(_) | CustomSource::File { .. } => None,
+        };
+        let regex = match &source {
+            CustomSource::File {
+                regex: Some(regex), ..
+            } => Some(Regex::new(regex).map_err(|err| format!("Invalid regex: {err}"))),
+            _ => None,
+        };
+        Self {
+            source,
+            child: None,
+            fifo_values,
+            fifo_stop,
+            regex,
+            last: None,
+            values: Vec::new(),
+            error: None,
+            histories: HashMap::new(),
+            empty: History::with_capacity(sampling_window),
+            sampling_window,
+        }
+    }
+
+    pub fn resize(&mut self, sampling_window: usize) {
+        self.sampling_window = sampling_window;
+        self.empty.resize(sampling_window);
+        for history in self.histories.values_mut() {
+            history.resize(sampling_window);
+        }
+    }
+
+    /// `update_interval` is in milliseconds, and turns the `Rate` mode into a rate per second
+    pub fn refresh(&mut self, update_interval: u64) {
+        if let Some(values) = self.collect_output() {
+            self.values = values;
+        }
+        match &self.source {
+            // A command slower than the update interval keeps running, and its previous values are reused
+            CustomSource::Command(command) => {
+                if self.child.is_none() {
+                    self.child = spawn_command(command);
+                }
+            }
+            CustomSource::Fifo(_) => {
+                if let Some(values) = &self.fifo_values
+                    && let Ok(values) = values.lock()
+                {
+                    self.values.clone_from(&values);
+                }
+            }
+            // An unreadable file or the first sample of a rate isn't charted, rather than charted as 0
+            CustomSource::File {
+                path,
+                line,
+                field,
+                scale,
+                mode,
+                ..
+            } => {
+                let path = Path::new(path);
+                let number = match &self.regex {
+                    Some(Err(error)) => Err(error.clone()),
+                    Some(Ok(regex)) => read_number(path, line.as_deref(), *field, Some(regex)),
+                    None => read_number(path, line.as_deref(), *field, None),
+                };
+                let number = match number {
+                    Ok(number) => number,
+                    Err(error) => {
+                        self.error = Some(error);
+                        self.last = None;
+                        return;
+                    }
+                };
+                self.error = None;
+                #[allow(clippy::cast_precision_loss)]
+                let value = match mode {
+                    FileMode::Gauge => number,
+                    FileMode::Rate => {
+                        let Some(last) = self.last.replace(number) else {
+                            return;
+                        };
+                        (number - last).max(0.0) * 1000.0 / update_interval.max(1) as f64
+                    }
+                };
+                let key = path.file_name().map_or_else(
+                    || "0".to_string(),
+                    |name| name.to_string_lossy().into_owned(),
+                );
+                #[allow(clippy::cast_possible_truncation)]
+                let value = value as f32 * scale;
+                self.values = vec![(key, value)];
+            }
+        }
+        for (key, value) in &self.values {
+            self.histories
+                .entry(key.clone())
+                .or_insert_with(|| History::with_capacity(self.sampling_window))
+                .push(*value);
+        }
+    }
+
+    /// Output of the previous command, once it exited
+    fn collect_output(&mut self) -> Option<Vec<(String, f32)>> {
+        let (mut child, output) = self.child.take()?;
+        // The output is only complete once stdout is closed, which processes started by the command may delay
+        match child.try_wait() {
+            Ok(Some(_)) if output.is_finished() => Some(parse_values(output.join().ok()?.lines())),
+            Ok(_) => {
+                self.child = Some((child, output));
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// The value named `key`, or the first one when `None`
+    pub fn value(&self, key: Option<&str>) -> Option<(&str, f32)> {
+        self.values
+            .iter()
+            .find(|(name, _)| key.is_none_or(|key| key == name))
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+
+    /// History of the value named `key`, or of the first one when `None`, empty until it is read
+    pub fn history(&self, key: Option<&str>) -> &History<f32> {
+        self.value(key)
+            .and_then(|(name, _)| self.histories.get(name))
+            .unwrap_or(&self.empty)
+    }
+}
+
+impl Drop for Custom {
+    fn drop(&mut self) {
+        self.fifo_stop.store(true, Ordering::Relaxed);
+        if let Some((child, _)) = &mut self.child {
+            _ = child.kill();
+            _ = child.wait();
+        }
+    }
+}
+
+/// Runs `command` with `sh -c`, reading its stdout in a thread so that it never blocks on a full pipe
+fn spawn_command(command: &str) -> Option<(Child, JoinHandle<String>)> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let stdout = child.stdout.take()?;
+    let output = std::thread::spawn(move || std::io::read_to_string(stdout).unwrap_or_default());
+    Some((child, output))
+}
+
+/// Reads the number of a `File` source: the whole file, or its `field`-th whitespace-separated field,
+/// after the `line` prefix when there is one, then what `regex` matches in it.
+///
+/// Without any of them, the file is read as an integer like other sysfs attributes.
+fn read_number(
+    path: &Path,
+    line: Option<&str>,
+    field: Option<usize>,
+    regex: Option<&Regex>,
+) -> Result<f64, String> {
+    if line.is_none() && field.is_none() && regex.is_none() {
+        #[allow(clippy::cast_precision_loss)]
+        return path
+            .file_name()
+            .and_then(|file| read_syspath(path.parent()?, file.to_str()?))
+            .map(|number| number as f64)
+            .ok_or_else(|| format!("{} can't be read as an integer", path.display()));
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| format!("Can't read {}: {err}", path.display()))?;
+    let text = match line {
+        Some(prefix) => content
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .ok_or_else(|| format!("No line starting with \"{prefix}\" in {}", path.display()))?,
+        None => content.as_str(),
+    };
+    let text = match field {
+        Some(index) => text
+            .split_whitespace()
+            .nth(index)
+            .ok_or_else(|| format!("No field {index} in {}", path.display()))?,
+        None => text.trim(),
+    };
+    let text = match regex {
+        Some(regex) => {
+            let captures = regex
+                .captures(text)
+                .ok_or_else(|| format!("No match for the regex in {}", path.display()))?;
+            let matched = captures.get(1).or_else(|| captures.get(0));
+            matched.map_or("", |matched| matched.as_str().trim())
+        }
+        None => text,
+    };
+    text.parse()
+        .map_err(|_| format!("\"{text}\" in {} isn't a number", path.display()))
+}
+
+/// Parses one value per line, either as `key=value` or as a bare number named after its line index
+fn parse_values<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<(String, f32)> {
+    lines
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .filter_map(|(index, line)| match line.split_once('=') {
+            Some((key, value)) => Some((key.trim().to_string(), value.trim().parse().ok()?)),
+            None => Some((index.to_string(), line.trim().parse().ok()?)),
+        })
+        .collect()
+}
+
+/// How often the FIFO is polled for new lines
+const FIFO_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Longest wait between attempts to open a FIFO that doesn't exist yet
+const FIFO_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Reads the FIFO in a thread, polling it without blocking so that the thread notices `stop`
+/// even when nothing is written, and retrying with a backoff while the FIFO can't be opened.
+///
+/// Each line updates the value with the same key, or the first value when it is a bare number.
+fn spawn_fifo_reader(path: String, stop: Arc<AtomicBool>) -> SharedValues {
+    let values = Arc::new(Mutex::new(Vec::new()));
+    let shared = Arc::clone(&values);
+    std::thread::spawn(move || {
+        let mut backoff = FIFO_POLL_INTERVAL;
+        while !stop.load(Ordering::Relaxed) {
+            // Without a writer, a non-blocking open succeeds and reads return nothing until one shows up
+            let Ok(file) = OpenOptions::new()
+                .read(true)
+                .custom_flags(libc::O_NONBLOCK)
+                .open(&path)
+            else {
+                std::thread::sleep(backoff);
+                backoff = (backoff * 2).min(FIFO_MAX_BACKOFF);
+                continue;
+            };
+            backoff = FIFO_POLL_INTERVAL;
+
+            let mut reader = BufReader::new(file);
+            let mut line = String::new();
+            while !stop.load(Ordering::Relaxed) {
+                match reader.read_line(&mut line) {
+                    // A partial line is kept until the rest of it is written
+                    Ok(_) if line.ends_with('\n') => {
+                        if let Some((key, value)) =
+                            parse_values(std::iter::once(line.as_str())).pop()
+                        {
+                            let Ok(mut values) = shared.lock() else {
+                                return;
+                            };
+                            match values.iter_mut().find(|(name, _)| *name == key) {
+                                Some((_, old)) => *old = value,
+                                None => values.push((key, value)),
+                            }
+                        }
+                        line.clear();
+                    }
+                    Ok(_) => std::thread::sleep(FIFO_POLL_INTERVAL),
+                    Err(err) if err.kind() == ErrorKind::WouldBlock => {
+                        std::thread::sleep(FIFO_POLL_INTERVAL);
+                    }
+                    // Reopen the FIFO
+                    Err(_) => break,
+                }
+            }
+        }
+    });
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Samples pushed to the history of `key`, which starts filled with zeroes
+    fn pushed(custom: &Custom, key: Option<&str>) -> Vec<f32> {
+        custom
+            .history(key)
+            .iter()
+            .copied()
+            .filter(|value| *value != 0.0)
+            .collect()
+    }
+
+    /// Writes `content` to a temporary file, unique to this test run so that concurrent runs don't race
+    fn temp_file(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn values(pairs: &[(&str, f32)]) -> Vec<(String, f32)> {
+        pairs
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), *value))
+            .collect()
+    }
+
+    /// Refreshes a command source until the command it started has exited and its output was collected
+    fn refresh_until_exited(custom: &mut Custom) {
+        custom.refresh(1000);
+        for _ in 0..200 {
+            let exited = custom.child.as_mut().is_none_or(|(child, output)| {
+                child.try_wait().is_ok_and(|status| status.is_some()) && output.is_finished()
+            });
+            if exited {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        custom.refresh(1000);
+    }
+
+    #[test]
+    fn parses_key_value_pairs() {
+        let parsed = parse_values(["queued=3", " running = 1.5 "].into_iter());
+        assert_eq!(parsed, values(&[("queued", 3.0), ("running", 1.5)]));
+    }
+
+    #[test]
+    fn names_bare_numbers_after_their_line() {
+        let parsed = parse_values(["4", "", "temp=40", "2.5"].into_iter());
+        assert_eq!(parsed, values(&[("0", 4.0), ("temp", 40.0), ("2", 2.5)]));
+    }
+
+    #[test]
+    fn skips_lines_that_are_not_numbers() {
+        let parsed = parse_values(["5", "oops", "key=value", "7"].into_iter());
+        assert_eq!(parsed, values(&[("0", 5.0), ("3", 7.0)]));
+    }
+
+    #[test]
+    fn reads_a_field_after_a_line_prefix() {
+        let path = temp_file(
+            "custom-read-number-test",
+            "MemTotal: 100 kB\nMemAvailable: 42 kB\n",
+        );
+        let number = read_number(&path, Some("MemAvailable:"), Some(0), None);
+        let missing = read_number(&path, Some("SwapTotal:"), Some(0), None);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(number, Ok(42.0));
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn reads_the_first_capture_group_of_the_regex() {
+        let path = temp_file("custom-read-regex-test", "0.52 0.58 0.59 1/467 12345\n");
+        let regex = Regex::new(r"^\S+ \S+ (\S+)").unwrap();
+        let number = read_number(&path, None, None, Some(&regex));
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(number, Ok(0.59));
+    }
+
+    #[test]
+    fn reports_an_invalid_regex() {
+        let source = CustomSource::File {
+            path: "/proc/loadavg".to_string(),
+            line: None,
+            field: None,
+            regex: Some("(".to_string()),
+            scale: 1.0,
+            mode: FileMode::Gauge,
+        };
+        let mut custom = Custom::new(source, 10);
+        custom.refresh(1000);
+        assert!(
+            custom
+                .error
+                .as_ref()
+                .is_some_and(|error| error.starts_with("Invalid regex"))
+        );
+        assert!(custom.values.is_empty());
+    }
+
+    #[test]
+    fn collects_the_output_of_an_exited_command() {
+        let source = CustomSource::Command("echo 1; echo load=2.5".to_string());
+        let mut custom = Custom::new(source, 10);
+        refresh_until_exited(&mut custom);
+        assert_eq!(custom.values, values(&[("0", 1.0), ("load", 2.5)]));
+        assert_eq!(pushed(&custom, Some("load")), [2.5]);
+    }
+
+    #[test]
+    fn collects_output_larger_than_the_pipe_buffer() {
+        let source = CustomSource::Command("seq 1 20000".to_string());
+        let mut custom = Custom::new(source, 10);
+        refresh_until_exited(&mut custom);
+        assert_eq!(custom.values.len(), 20000);
+    }
+
+    #[test]
+    fn slow_command_keeps_its_previous_values() {
+        let mut custom = Custom::new(CustomSource::Command("sleep 5".to_string()), 10);
+        custom.values = values(&[("queued", 2.0)]);
+        custom.refresh(1000);
+        custom.refresh(1000);
+        assert_eq!(custom.values, values(&[("queued", 2.0)]));
+        assert_eq!(pushed(&custom, None), [2.0, 2.0]);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::procfs::Counter;
+
+/// Activity of a block device between refreshes, from `/proc/diskstats`
+#[derive(Clone, Copy, Default)]
+pub struct DeviceData {
+    /// read requests completed
+    pub reads: u64,
+    /// write requests completed
+    pub writes: u64,
+    /// milliseconds spent by all read requests
+    pub read_time: u64,
+    /// milliseconds spent by all write requests
+    pub write_time: u64,
+    /// requests currently being processed
+    pub in_flight: u64,
+    /// milliseconds the device was busy
+    pub busy_time: u64,
+}
+
+impl DeviceData {
+    /// Average time (in microseconds) a request took to complete, including time queued
+    pub fn latency(&self) -> u64 {
+        let requests = self.reads + self.writes;
+        if requests == 0 {
+            0
+        } else {
+            (self.read_time + self.write_time) * 1000 / requests
+        }
+    }
+}
+
+impl std::ops::Add for DeviceData {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            reads: self.reads + rhs.reads,
+            writes: self.writes + rhs.writes,
+            read_time: self.read_time + rhs.read_time,
+            write_time: self.write_time + rhs.write_time,
+            in_flight: self.in_flight + rhs.in_flight,
+            busy_time: self.busy_time + rhs.busy_time,
+        }
+    }
+}
+
+#[derive(Default)]
+struct DeviceCounters {
+    reads: Counter,
+    writes: Counter,
+    read_time: Counter,
+    write_time: Counter,
+    busy_time: Counter,
+}
+
+#[derive(Default)]
+pub struct DiskStats {
+    counters: HashMap<String, DeviceCounters>,
+    /// activity of each physical device, by name
+    pub data: Vec<(String, DeviceData)>,
+}
+
+impl DiskStats {
+    pub fn refresh(&mut self) {
+        let Ok(content) = std::fs::read_to_string("/proc/diskstats") else {
+            return;
+        };
+        self.data = content
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<_> = line.split_whitespace().collect();
+                let name = *fields.get(2)?;
+                // Partitions, loop, zram and device mapper devices don't have a `device`,
+                // and would count the same requests twice
+                if !Path::new("/sys/block").join(name).join("device").exists() {
+                    return None;
+                }
+                let read = |index: usize| fields.get(index)?.parse::<u64>().ok();
+                let counters = self.counters.entry(name.to_string()).or_default();
+                let data = DeviceData {
+                    reads: counters.reads.delta(read(3)?),
+                    read_time: counters.read_time.delta(read(6)?),
+                    writes: counters.writes.delta(read(7)?),
+                    write_time: counters.write_time.delta(read(10)?),
+                    in_flight: read(11)?,
+                    busy_time: counters.busy_time.delta(read(12)?),
+                };
+                Some((name.to_string(), data))
+            })
+            .collect();
+    }
+
+    pub fn total(&self) -> DeviceData {
+        self.data
+            .iter()
+            .fold(DeviceData::default(), |acc, (_, data)| acc + *data)
+    }
+
+    /// The device that was busy the longest, since utilization doesn't add up across devices
+    pub fn busiest(&self) -> Option<&(String, DeviceData)> {
+        self.data.iter().max_by_key(|(_, data)| data.busy_time)
+    }
+}
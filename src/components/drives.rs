@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use super::{gpu::read_syspath, hwmon};
+
+/// Temperature of a storage device, from the `nvme` and `drivetemp` hwmon drivers
+#[derive(Clone, Debug)]
+pub struct DriveData {
+    /// block or controller name, e.g. `nvme0` or `sda`
+    pub name: String,
+    /// in degrees Celsius
+    pub temperature: f32,
+    /// temperature above which the drive reports a warning, in degrees Celsius
+    pub warning: Option<f32>,
+    /// temperature above which the drive may shut down, in degrees Celsius
+    pub critical: Option<f32>,
+    /// set by the drive itself, e.g. the NVMe critical warning temperature bit
+    ///
+    /// The other critical warning bits and the percentage used are only in the NVMe SMART log, which needs root.
+    pub alarm: bool,
+}
+
+impl DriveData {
+    pub fn read_all() -> Vec<Self> {
+        let mut drives: Vec<_> = hwmon::chips()
+            .into_iter()
+            .filter(|(name, _)| name == "nvme" || name == "drivetemp")
+            .filter_map(|(_, path)| Self::read(&path))
+            .collect();
+        drives.sort_by(|a, b| a.name.cmp(&b.name));
+        drives
+    }
+
+    fn read(path: &Path) -> Option<Self> {
+        // Values are in millidegrees Celsius
+        #[allow(clippy::cast_precision_loss)]
+        let read_temp = |file| read_syspath(path, file).map(|value| value as f32 / 1000.0);
+        Some(Self {
+            name: drive_name(path)?,
+            temperature: read_temp("temp1_input")?,
+            warning: read_temp("temp1_max"),
+            critical: read_temp("temp1_crit"),
+            alarm: read_syspath(path, "temp1_alarm").is_some_and(|alarm| alarm != 0),
+        })
+    }
+
+    /// The temperature at which the drive should be considered too hot
+    pub fn threshold(&self) -> Option<f32> {
+        self.warning.or(self.critical)
+    }
+
+    pub fn is_overheating(&self) -> bool {
+        self.alarm || self.threshold().is_some_and(|t| self.temperature >= t)
+    }
+}
+
+/// SATA drives have their block device under `device/block`, while `device` is the NVMe controller itself
+fn drive_name(path: &Path) -> Option<String> {
+    let device = path.join("device");
+    if let Some(block) = std::fs::read_dir(device.join("block"))
+        .ok()
+        .and_then(|mut entries| entries.next()?.ok())
+    {
+        return Some(block.file_name().to_string_lossy().into_owned());
+    }
+    let device = device.canonicalize().ok()?;
+    Some(device.file_name()?.to_string_lossy().into_owned())
+}
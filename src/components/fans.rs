@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{gpu::read_syspath, hwmon};
+use crate::history::History;
+
+/// A fan reported by a hwmon chip, e.g. `nct6775`, `thinkpad` or `amdgpu`
+#[derive(Clone, Debug)]
+pub struct FanData {
+    /// the chip name and the label, which tells apart fans with the same label on different chips
+    pub name: String,
+    /// `fan*_label` when the driver has one, otherwise the fan number
+    pub label: String,
+    pub rpm: u64,
+    /// `fan*_max`, which few drivers report
+    pub max: Option<u64>,
+    /// highest speed seen since the applet started
+    pub peak: u64,
+}
+
+impl FanData {
+    /// `max_rpm` when configured, otherwise `fan*_max`, otherwise the highest speed seen
+    pub fn max_rpm(&self, max_rpm: Option<u64>) -> u64 {
+        max_rpm.or(self.max).unwrap_or(self.peak)
+    }
+}
+
+/// The fans of all hwmon chips, and the history of their speed
+pub struct Fans {
+    pub data: Vec<FanData>,
+    peaks: HashMap<String, u64>,
+    histories: HashMap<String, History>,
+    /// history of fans that were never read, so their charts stay empty
+    empty: History,
+    sampling_window: usize,
+}
+
+impl Fans {
+    pub fn new(sampling_window: usize) -> Self {
+        Self {
+            data: Vec::new(),
+            peaks: HashMap::new(),
+            histories: HashMap::new(),
+            empty: History::with_capacity(sampling_window),
+            sampling_window,
+        }
+    }
+
+    pub fn resize(&mut self, sampling_window: usize) {
+        self.sampling_window = sampling_window;
+        for history in self.histories.values_mut() {
+            history.resize(sampling_window);
+        }
+        self.empty.resize(sampling_window);
+    }
+
+    pub fn refresh(&mut self) {
+        let mut data: Vec<_> = hwmon::chips()
+            .into_iter()
+            .flat_map(|(chip, path)| read_chip(&chip, &path))
+            .collect();
+        data.sort_by(|a, b| a.name.cmp(&b.name));
+        for fan in &mut data {
+            let peak = self.peaks.entry(fan.name.clone()).or_default();
+            *peak = fan.rpm.max(*peak);
+            fan.peak = *peak;
+            self.histories
+                .entry(fan.name.clone())
+                .or_insert_with(|| History::with_capacity(self.sampling_window))
+                .push(fan.rpm);
+        }
+        // Forget fans that went away, e.g. with an unplugged GPU
+        self.peaks
+            .retain(|name, _| data.iter().any(|fan| fan.name == *name));
+        self.histories
+            .retain(|name, _| data.iter().any(|fan| fan.name == *name));
+        self.data = data;
+    }
+
+    /// The fan named `name`, or else the first one labeled `name`
+    pub fn get(&self, name: &str) -> Option<&FanData> {
+        self.data
+            .iter()
+            .find(|fan| fan.name == name)
+            .or_else(|| self.data.iter().find(|fan| fan.label == name))
+    }
+
+    /// History of the fan `name` refers to, as in [`Fans::get`], empty when no such fan was read
+    pub fn history(&self, name: &str) -> &History {
+        self.get(name)
+            .and_then(|fan| self.histories.get(&fan.name))
+            .unwrap_or(&self.empty)
+    }
+}
+
+/// Reads every `fan*_input` of a chip, along with the matching `fan*_label` and `fan*_max`
+fn read_chip(chip: &str, path: &Path) -> Vec<FanData> {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| {
+            let file = entry.ok()?.file_name();
+            let fan = file.to_str()?.strip_suffix("_input")?;
+            if !fan.starts_with("fan") {
+                return None;
+            }
+            let label = std::fs::read_to_string(path.join(format!("{fan}_label")))
+                .map(|label| label.trim_end().to_string())
+                .unwrap_or_else(|_| fan.to_string());
+            Some(FanData {
+                name: format!("{chip} {label}"),
+                label,
+                rpm: read_syspath(path, &format!("{fan}_input"))?,
+                max: read_syspath(path, &format!("{fan}_max")).filter(|max| *max > 0),
+                peak: 0,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use cosmic::{
+    Element, Renderer, Theme,
+    cosmic_theme::palette::WithAlpha,
+    iced::{Point, Radians, Rectangle, core::mouse},
+    widget::{
+        Canvas,
+        canvas::{Frame, Geometry, Path, Program, Stroke, path, stroke},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use super::bar::percentage;
+use crate::{applet::Message, color::Color};
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub enum GaugeShape {
+    /// Full circle, starting at the top
+    #[default]
+    Donut,
+    /// Three quarters of a circle, open at the bottom
+    Arc,
+}
+
+impl GaugeShape {
+    /// Angle where the rings start, clockwise from the right
+    fn start(self) -> f32 {
+        match self {
+            GaugeShape::Donut => -FRAC_PI_2,
+            GaugeShape::Arc => 3.0 * PI / 4.0,
+        }
+    }
+
+    /// Angle covered by a full ring
+    fn sweep(self) -> f32 {
+        match self {
+            GaugeShape::Donut => 2.0 * PI,
+            GaugeShape::Arc => 3.0 * PI / 2.0,
+        }
+    }
+}
+
+/// Concentric rings filled according to a percentage, the first one being the outermost
+#[derive(Debug)]
+pub struct Gauge {
+    shape: GaugeShape,
+    rings: Vec<(f32, Color)>,
+}
+
+impl Gauge {
+    pub fn new(shape: GaugeShape, percentage: f32, color: Color) -> Self {
+        Self {
+            shape,
+            rings: vec![(percentage, color)],
+        }
+    }
+
+    pub fn from_pair(shape: GaugeShape, current: u64, max: u64, color: Color) -> Self {
+        Self::new(shape, percentage(current, max), color)
+    }
+
+    /// Adds a ring inside the existing ones
+    pub fn with_ring(mut self, percentage: f32, color: Color) -> Self {
+        self.rings.push((percentage, color));
+        self
+    }
+
+    pub fn with_ring_pair(self, current: u64, max: u64, color: Color) -> Self {
+        self.with_ring(percentage(current, max), color)
+    }
+}
+
+impl<'a> From<Gauge> for Element<'a, Message> {
+    fn from(value: Gauge) -> Self {
+        Canvas::new(value).into()
+    }
+}
+
+impl Program<Message, Theme, Renderer> for Gauge {
+    type State = ();
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let center = Point {
+            x: bounds.width / 2.0,
+            y: bounds.height / 2.0,
+        };
+        let outer_radius = bounds.width.min(bounds.height) / 2.0;
+        // Rings and the gaps between them share the outer half of the radius
+        let thickness = outer_radius / 2.0 / (self.rings.len() as f32 * 1.5 - 0.5).max(1.0);
+        let start = self.shape.start();
+
+        for (index, (percentage, color)) in self.rings.iter().enumerate() {
+            let radius = outer_radius - thickness * (0.5 + index as f32 * 1.5);
+            let color = color.as_cosmic_color(theme);
+            let ring = |sweep: f32| {
+                let mut builder = path::Builder::new();
+                builder.arc(path::Arc {
+                    center,
+                    radius,
+                    start_angle: Radians(start),
+                    end_angle: Radians(start + sweep),
+                });
+                builder.build()
+            };
+            let track = match self.shape {
+                GaugeShape::Donut => Path::circle(center, radius),
+                GaugeShape::Arc => ring(self.shape.sweep()),
+            };
+            frame.stroke(
+                &track,
+                Stroke {
+                    style: stroke::Style::Solid(color.with_alpha(0.25).into()),
+                    width: thickness,
+                    ..Default::default()
+                },
+            );
+            let filled = percentage.clamp(0.0, 100.0) / 100.0;
+            if filled > 0.0 {
+                frame.stroke(
+                    &ring(self.shape.sweep() * filled),
+                    Stroke {
+                        style: stroke::Style::Solid(color.into()),
+                        width: thickness,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        vec![frame.into_geometry()]
+    }
+}
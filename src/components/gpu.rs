@@ -96,7 +96,7 @@ impl Gpus {
     }
 }
 
-fn read_syspath(sysfs_path: &Path, file: &str) -> Option<u64> {
+pub fn read_syspath(sysfs_path: &Path, file: &str) -> Option<u64> {
     std::fs::read_to_string(sysfs_path.join(file))
         .ok()
         .and_then(|s| s.trim_end().parse().ok())
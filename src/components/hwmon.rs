@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+/// Lists the hardware monitoring chips in `/sys/class/hwmon`, with the content of their `name` file.
+///
+/// These files are readable without root.
+pub fn chips() -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir("/sys/class/hwmon") else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = std::fs::read_to_string(path.join("name")).ok()?;
+            Some((name.trim_end().to_string(), path))
+        })
+        .collect()
+}
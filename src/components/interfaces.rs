@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use sysinfo::Networks;
+
+use super::{gpu::read_syspath, procfs::Counter};
+
+/// Packets of a network interface between refreshes
+#[derive(Clone, Copy, Default)]
+pub struct PacketData {
+    pub received: u64,
+    pub transmitted: u64,
+    /// packets that failed to be received or transmitted
+    pub errors: u64,
+    /// packets dropped while receiving or transmitting
+    pub drops: u64,
+}
+
+impl std::ops::Add for PacketData {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            received: self.received + rhs.received,
+            transmitted: self.transmitted + rhs.transmitted,
+            errors: self.errors + rhs.errors,
+            drops: self.drops + rhs.drops,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct InterfaceStats {
+    /// `sysinfo` doesn't have dropped packets, so these are read from sysfs
+    drops: HashMap<String, Counter>,
+    /// packets of each interface, by name
+    pub data: Vec<(String, PacketData)>,
+    /// speed of the fastest link, in bytes per second
+    pub link_speed: Option<u64>,
+}
+
+impl InterfaceStats {
+    /// Should be called right after refreshing `networks`
+    pub fn refresh(&mut self, networks: &Networks) {
+        self.data = networks
+            .iter()
+            .map(|(name, data)| {
+                let statistics = Path::new("/sys/class/net").join(name).join("statistics");
+                let dropped = read_syspath(&statistics, "rx_dropped").unwrap_or_default()
+                    + read_syspath(&statistics, "tx_dropped").unwrap_or_default();
+                let packets = PacketData {
+                    received: data.packets_received(),
+                    transmitted: data.packets_transmitted(),
+                    errors: data.errors_on_received() + data.errors_on_transmitted(),
+                    drops: self.drops.entry(name.clone()).or_default().delta(dropped),
+                };
+                (name.clone(), packets)
+            })
+            .collect();
+        // In Mbit/s, and unreadable (or -1) for interfaces without a link, such as loopback or Wi-Fi
+        self.link_speed = networks
+            .keys()
+            .filter_map(|name| read_syspath(&Path::new("/sys/class/net").join(name), "speed"))
+            .filter(|&mbits| mbits > 0)
+            .max()
+            .map(|mbits| mbits * 1_000_000 / 8);
+    }
+
+    pub fn total(&self) -> PacketData {
+        self.data
+            .iter()
+            .fold(PacketData::default(), |acc, (_, packets)| acc + *packets)
+    }
+}
@@ -0,0 +1,31 @@
+use super::procfs::{Counter, read_table};
+
+/// Kernel activity between refreshes, from `/proc/stat`
+#[derive(Clone, Copy, Default)]
+pub struct KernelData {
+    pub context_switches: u64,
+    pub interrupts: u64,
+    /// processes and threads created
+    pub forks: u64,
+}
+
+#[derive(Default)]
+pub struct KernelActivity {
+    context_switches: Counter,
+    interrupts: Counter,
+    forks: Counter,
+    pub data: KernelData,
+}
+
+impl KernelActivity {
+    pub fn refresh(&mut self) {
+        let stat = read_table("/proc/stat");
+        let read = |key: &str| stat.get(key).copied().unwrap_or_default();
+        self.data = KernelData {
+            context_switches: self.context_switches.delta(read("ctxt")),
+            // The first number of the `intr` line is the total of all interrupts
+            interrupts: self.interrupts.delta(read("intr")),
+            forks: self.forks.delta(read("processes")),
+        };
+    }
+}
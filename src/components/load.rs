@@ -0,0 +1,29 @@
+use sysinfo::System;
+
+use super::procfs::read_table;
+
+#[derive(Clone, Copy, Default)]
+pub struct LoadData {
+    pub one: f32,
+    pub five: f32,
+    pub fifteen: f32,
+    /// processes currently runnable (`procs_running` in `/proc/stat`)
+    pub running: u64,
+    /// processes blocked waiting on I/O (`procs_blocked` in `/proc/stat`)
+    pub blocked: u64,
+}
+
+impl LoadData {
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn read() -> Self {
+        let load = System::load_average();
+        let stat = read_table("/proc/stat");
+        Self {
+            one: load.one as f32,
+            five: load.five as f32,
+            fifteen: load.fifteen as f32,
+            running: stat.get("procs_running").copied().unwrap_or_default(),
+            blocked: stat.get("procs_blocked").copied().unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+/// Breakdown of RAM from `/proc/meminfo`, in bytes
+///
+/// The `used`, `shared`, `buffers`, `cache` and `dirty` fields don't overlap, so they can be stacked.
+#[derive(Clone, Copy, Default)]
+pub struct MemInfo {
+    pub total: u64,
+    /// memory that can be given to new allocations without swapping
+    pub available: u64,
+    /// memory that isn't free, buffers, cache or shared (same as `free`)
+    pub used: u64,
+    /// tmpfs and shared memory
+    pub shared: u64,
+    pub buffers: u64,
+    /// page cache and reclaimable slab, without shared memory and dirty pages
+    pub cache: u64,
+    /// dirty pages and pages being written back to disk
+    pub dirty: u64,
+}
+
+impl MemInfo {
+    /// Parses the `/proc/meminfo` table, whose values are in KiB
+    pub fn from_table(table: &HashMap<String, u64>) -> Self {
+        let read = |key: &str| table.get(key).copied().unwrap_or_default() * 1024;
+
+        let total = read("MemTotal");
+        let shared = read("Shmem");
+        let buffers = read("Buffers");
+        let dirty = read("Dirty") + read("Writeback");
+        let cached = read("Cached") + read("SReclaimable");
+        Self {
+            total,
+            available: read("MemAvailable"),
+            used: total.saturating_sub(read("MemFree") + buffers + cached),
+            shared,
+            buffers,
+            cache: cached.saturating_sub(shared + dirty),
+            dirty,
+        }
+    }
+}
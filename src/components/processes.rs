@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+
+/// Resource used to rank processes
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub enum ProcessSort {
+    #[default]
+    Cpu,
+    Memory,
+    DiskIo,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProcessData {
+    pub name: String,
+    /// percentage of a single core, so it can go over 100%
+    pub cpu: f32,
+    /// resident memory in bytes
+    pub memory: u64,
+    /// amount read and written between refreshes. (NOT A RATE)
+    pub disk_io: u64,
+}
+
+impl ProcessData {
+    /// Refreshes the processes in `sys`, and returns their usage (threads are skipped)
+    pub fn read_all(sys: &mut System) -> Vec<Self> {
+        sys.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_disk_usage(),
+        );
+        sys.processes()
+            .values()
+            .filter(|process| process.thread_kind().is_none())
+            .map(|process| {
+                let disk_usage = process.disk_usage();
+                Self {
+                    name: process.name().to_string_lossy().into_owned(),
+                    cpu: process.cpu_usage(),
+                    memory: process.memory(),
+                    disk_io: disk_usage.read_bytes + disk_usage.written_bytes,
+                }
+            })
+            .collect()
+    }
+}
+
+impl ProcessSort {
+    fn compare(self, a: &ProcessData, b: &ProcessData) -> Ordering {
+        match self {
+            ProcessSort::Cpu => b.cpu.partial_cmp(&a.cpu).unwrap_or(Ordering::Equal),
+            ProcessSort::Memory => b.memory.cmp(&a.memory),
+            ProcessSort::DiskIo => b.disk_io.cmp(&a.disk_io),
+        }
+    }
+
+    /// The `count` processes using the most of this resource, from highest to lowest
+    pub fn top(self, processes: &[ProcessData], count: usize) -> Vec<&ProcessData> {
+        let mut sorted: Vec<_> = processes.iter().collect();
+        sorted.sort_unstable_by(|a, b| self.compare(a, b));
+        sorted.truncate(count);
+        sorted
+    }
+}
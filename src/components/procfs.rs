@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Page size of the running kernel, for the counters that are in pages
+///
+/// It isn't always 4 KiB, e.g. on arm64 kernels built with 16 KiB or 64 KiB pages.
+pub fn page_size() -> u64 {
+    u64::try_from(rustix::param::page_size()).unwrap_or(4096)
+}
+
+/// Parses files made of `key value...` lines, such as `/proc/stat`, `/proc/vmstat` or `/proc/meminfo`.
+///
+/// Only the first number after each key is kept, and a trailing `:` is stripped from the key.
+/// Unreadable files yield an empty table, so callers can fall back to zeroes.
+pub fn read_table(path: impl AsRef<Path>) -> HashMap<String, u64> {
+    std::fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| {
+                    let mut fields = line.split_whitespace();
+                    let key = fields.next()?.trim_end_matches(':');
+                    let value = fields.next()?.parse().ok()?;
+                    Some((key.to_string(), value))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Turns a monotonically increasing kernel counter into the amount it grew between samples.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Counter {
+    last: Option<u64>,
+}
+
+impl Counter {
+    /// Returns 0 on the first sample, and whenever the counter goes backwards (e.g. after a reset).
+    pub fn delta(&mut self, value: u64) -> u64 {
+        let delta = self.last.map_or(0, |last| value.saturating_sub(last));
+        self.last = Some(value);
+        delta
+    }
+
+    /// Same as [`Counter::delta`] for counters that start over from 0 past `range`, such as RAPL energy counters,
+    /// whose going backwards is a wrap rather than a reset.
+    pub fn wrapping_delta(&mut self, value: u64, range: u64) -> u64 {
+        let delta = self.last.map_or(0, |last| {
+            if value >= last {
+                value - last
+            } else {
+                range.saturating_sub(last) + value
+            }
+        });
+        self.last = Some(value);
+        delta
+    }
+}
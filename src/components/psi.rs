@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+use super::procfs::Counter;
+
+/// Resources with Pressure Stall Information in `/proc/pressure`
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub enum PsiResource {
+    #[default]
+    Cpu,
+    Memory,
+    Io,
+}
+
+impl PsiResource {
+    pub const ALL: [PsiResource; 3] = [PsiResource::Cpu, PsiResource::Memory, PsiResource::Io];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PsiResource::Cpu => "CPU",
+            PsiResource::Memory => "Memory",
+            PsiResource::Io => "IO",
+        }
+    }
+
+    fn path(self) -> &'static str {
+        match self {
+            PsiResource::Cpu => "/proc/pressure/cpu",
+            PsiResource::Memory => "/proc/pressure/memory",
+            PsiResource::Io => "/proc/pressure/io",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct PressureData {
+    /// percentage of time at least one task was stalled, averaged over 10 seconds
+    pub some_avg10: f32,
+    /// percentage of time all non-idle tasks were stalled at once, averaged over 10 seconds
+    pub full_avg10: f32,
+    /// microseconds at least one task was stalled between refreshes
+    pub some_stalled: u64,
+    /// microseconds all non-idle tasks were stalled between refreshes
+    pub full_stalled: u64,
+}
+
+#[derive(Default)]
+struct Pressure {
+    some_total: Counter,
+    full_total: Counter,
+    data: PressureData,
+}
+
+#[derive(Default)]
+pub struct Pressures {
+    inner: [Pressure; 3],
+}
+
+impl Pressures {
+    pub fn refresh(&mut self) {
+        for resource in PsiResource::ALL {
+            // Kernels without PSI (or with it disabled) don't have these files, so everything stays at 0
+            let Ok(content) = std::fs::read_to_string(resource.path()) else {
+                continue;
+            };
+            let pressure = &mut self.inner[resource.index()];
+            for (kind, avg10, total) in content.lines().filter_map(parse_line) {
+                match kind {
+                    "some" => {
+                        pressure.data.some_avg10 = avg10;
+                        pressure.data.some_stalled = pressure.some_total.delta(total);
+                    }
+                    "full" => {
+                        pressure.data.full_avg10 = avg10;
+                        pressure.data.full_stalled = pressure.full_total.delta(total);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    pub fn data(&self, resource: PsiResource) -> PressureData {
+        self.inner[resource.index()].data
+    }
+}
+
+/// Parses lines like `some avg10=0.00 avg60=0.00 avg300=0.00 total=0`
+fn parse_line(line: &str) -> Option<(&str, f32, u64)> {
+    let mut fields = line.split_whitespace();
+    let kind = fields.next()?;
+    let (mut avg10, mut total) = (None, None);
+    for field in fields {
+        match field.split_once('=')? {
+            ("avg10", value) => avg10 = value.parse().ok(),
+            ("total", value) => total = value.parse().ok(),
+            _ => {}
+        }
+    }
+    Some((kind, avg10?, total?))
+}
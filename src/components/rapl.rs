@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{gpu::read_syspath, procfs::Counter};
+
+const POWERCAP_ROOT: &str = "/sys/class/powercap";
+
+/// Power domains of RAPL (Running Average Power Limit), available on Intel and AMD CPUs
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+pub enum PowerDomain {
+    /// The whole CPU package, including cores and uncore
+    #[default]
+    Package,
+    Core,
+    /// Integrated GPU, caches and memory controller
+    Uncore,
+    Dram,
+}
+
+impl PowerDomain {
+    pub const ALL: [PowerDomain; 4] = [
+        PowerDomain::Package,
+        PowerDomain::Core,
+        PowerDomain::Uncore,
+        PowerDomain::Dram,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PowerDomain::Package => "Package",
+            PowerDomain::Core => "Core",
+            PowerDomain::Uncore => "Uncore",
+            PowerDomain::Dram => "DRAM",
+        }
+    }
+
+    /// Zones are named `package-0`, `core`, `uncore` or `dram`
+    fn from_zone_name(name: &str) -> Option<Self> {
+        match name {
+            "core" => Some(PowerDomain::Core),
+            "uncore" => Some(PowerDomain::Uncore),
+            "dram" => Some(PowerDomain::Dram),
+            _ if name.starts_with("package-") => Some(PowerDomain::Package),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Rapl {
+    counters: HashMap<PathBuf, Counter>,
+    /// microjoules consumed between refreshes, indexed by [`PowerDomain::index`]
+    energy: [u64; 4],
+    /// whether at least one zone of the domain could be read, indexed by [`PowerDomain::index`]
+    available: [bool; 4],
+    /// long term power limit of all packages, in microwatts
+    pub package_limit: Option<u64>,
+}
+
+impl Rapl {
+    pub fn refresh(&mut self) {
+        self.energy = [0; 4];
+        self.available = [false; 4];
+        self.package_limit = None;
+        let Ok(entries) = std::fs::read_dir(POWERCAP_ROOT) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            // `intel-rapl-mmio` zones duplicate the package zones on some Intel CPUs
+            let zone = entry.file_name();
+            if !zone.to_string_lossy().starts_with("intel-rapl:") {
+                continue;
+            }
+            let path = entry.path();
+            let Some(domain) = std::fs::read_to_string(path.join("name"))
+                .ok()
+                .and_then(|name| PowerDomain::from_zone_name(name.trim_end()))
+            else {
+                continue;
+            };
+            // Only readable by root by default, since recent kernels
+            let Some(energy) = read_syspath(&path, "energy_uj") else {
+                continue;
+            };
+            let counter = self.counters.entry(path.clone()).or_default();
+            // `energy_uj` starts over from 0 once it reaches `max_energy_range_uj`, every few minutes to hours
+            self.energy[domain.index()] += match read_syspath(&path, "max_energy_range_uj") {
+                Some(range) => counter.wrapping_delta(energy, range),
+                None => counter.delta(energy),
+            };
+            self.available[domain.index()] = true;
+            if domain == PowerDomain::Package
+                && let Some(limit) = read_syspath(&path, "constraint_0_power_limit_uw")
+            {
+                *self.package_limit.get_or_insert(0) += limit;
+            }
+        }
+    }
+
+    /// Energy consumed between refreshes, in microjoules
+    pub fn energy(&self, domain: PowerDomain) -> u64 {
+        self.energy[domain.index()]
+    }
+
+    pub fn is_available(&self, domain: PowerDomain) -> bool {
+        self.available[domain.index()]
+    }
+}
@@ -1,20 +1,270 @@
 use cosmic::{
     Element, Renderer, Theme,
     cosmic_theme::palette::WithAlpha,
-    iced::{Point, Rectangle, core::mouse},
+    iced::{Pixels, Point, Rectangle, Vector, core::mouse},
+    theme::CosmicColor,
     widget::{
         Canvas,
-        canvas::{Fill, Frame, Geometry, Program, Stroke, path, stroke},
+        canvas::{
+            Fill, Frame, Geometry, Path, Program, Stroke, Text, gradient::Linear, path, stroke,
+        },
     },
 };
 
-use crate::{applet::Message, color::Color, history::History};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    applet::Message,
+    color::{Color, FillStyle, Thresholds},
+    history::History,
+};
+
+/// How the y axis of an IO run chart is scaled
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum Scale {
+    /// Linear, up to the highest sample
+    #[default]
+    Auto,
+    /// Linear, up to a rate in bytes per second, above which samples are clipped
+    Fixed(u64),
+    /// Linear, up to the speed of the fastest network link, or `Auto` when it is unknown, which it always is for disks
+    LinkSpeed,
+    /// Logarithmic, up to the highest sample, so that small rates remain visible next to bursts
+    #[serde(alias = "Log")]
+    Logarithmic,
+}
+
+impl Scale {
+    /// Per-interval max of the chart, `None` when it should be scaled to its highest sample
+    pub fn max(self, update_interval: u64, link_speed: Option<u64>) -> Option<u64> {
+        let per_second = match self {
+            Scale::Auto | Scale::Logarithmic => None,
+            Scale::Fixed(max) => Some(max),
+            Scale::LinkSpeed => link_speed,
+        };
+        per_second.map(|max| max.saturating_mul(update_interval) / 1000)
+    }
+
+    pub fn is_logarithmic(self) -> bool {
+        self == Scale::Logarithmic
+    }
+}
+
+/// Fraction of the chart height reached by `value`, clipped to the top
+fn fraction(value: f32, max: f32, logarithmic: bool) -> f32 {
+    if max <= 0.0 {
+        return 0.0;
+    }
+    let fraction = if logarithmic {
+        value.max(0.0).ln_1p() / max.ln_1p()
+    } else {
+        value / max
+    };
+    fraction.min(1.0)
+}
+
+/// Optional decorations drawn on every run chart
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
+pub struct Decorations {
+    /// Heights of horizontal gridlines, as fractions of the chart height, e.g. `[0.25, 0.5, 0.75]`
+    pub grid: Vec<f32>,
+    /// Writes the value at the top of IO charts in their top left corner
+    pub max_label: bool,
+    /// Marks the highest sample of the window with a dot
+    pub peak_marker: bool,
+}
+
+const LABEL_SIZE: f32 = 9.0;
+
+/// Decorations of a chart, and the values at the top of its scale
+#[derive(Debug, Default)]
+struct Overlay<'a> {
+    decorations: Option<&'a Decorations>,
+    top_label: Option<String>,
+    /// for charts growing down from the middle
+    bottom_label: Option<String>,
+}
+
+impl Overlay<'_> {
+    /// Gridlines measured from the bottom, or from the middle in both directions when `mirrored`
+    fn grid(
+        &self,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        mirrored: bool,
+    ) -> Geometry<Renderer> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        let color = theme.cosmic().background.on.with_alpha(0.25);
+        let fractions = self
+            .decorations
+            .map_or(&[][..], |decorations| decorations.grid.as_slice());
+        for fraction in fractions {
+            let heights = if mirrored {
+                let middle = bounds.height / 2.0;
+                vec![middle * (1.0 - fraction), middle * (1.0 + fraction)]
+            } else {
+                vec![bounds.height * (1.0 - fraction)]
+            };
+            for y in heights {
+                frame.stroke(
+                    &Path::line(Point { x: 0.0, y }, Point { x: bounds.width, y }),
+                    Stroke {
+                        style: stroke::Style::Solid(color.into()),
+                        width: 1.0,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+        frame.into_geometry()
+    }
+
+    fn labels(&self, renderer: &Renderer, theme: &Theme, bounds: Rectangle) -> Geometry<Renderer> {
+        let mut frame = Frame::new(renderer, bounds.size());
+        if self
+            .decorations
+            .is_some_and(|decorations| decorations.max_label)
+        {
+            let color = theme.cosmic().background.on;
+            let labels = [
+                (&self.top_label, 1.0),
+                (&self.bottom_label, bounds.height - LABEL_SIZE * 1.3),
+            ];
+            for (label, y) in labels {
+                if let Some(label) = label {
+                    frame.fill_text(Text {
+                        content: label.clone(),
+                        position: Point { x: 2.0, y },
+                        color: color.into(),
+                        size: Pixels(LABEL_SIZE),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+        frame.into_geometry()
+    }
+
+    fn peak_marker(&self) -> bool {
+        self.decorations
+            .is_some_and(|decorations| decorations.peak_marker)
+    }
+}
+
+/// Index and value of the highest sample, `None` when all of them are zero
+fn peak(values: impl Iterator<Item = f32>) -> Option<(usize, f32)> {
+    values
+        .enumerate()
+        .filter(|(_, value)| *value > 0.0)
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+}
+
+fn draw_peak_marker(frame: &mut Frame, position: Point, color: impl Into<cosmic::iced::Color>) {
+    frame.fill(
+        &Path::circle(position, 2.0),
+        Fill {
+            style: stroke::Style::Solid(color.into()),
+            ..Default::default()
+        },
+    );
+}
+
+/// Styles of the line of a chart and of the area under it
+struct ChartStyle {
+    line: stroke::Style,
+    area: stroke::Style,
+}
+
+impl ChartStyle {
+    /// The color of the line, and a lighter area
+    fn solid(color: CosmicColor) -> Self {
+        ChartStyle {
+            line: stroke::Style::Solid(color.into()),
+            area: stroke::Style::Solid(color.with_alpha(0.5).into()),
+        }
+    }
+
+    /// Styles of a chart growing from the `base` to the `top` of its scale `max`, both vertical positions
+    #[allow(clippy::cast_precision_loss)]
+    fn new<T>(chart: &HistoryChart<T>, max: f32, theme: &Theme, base: f32, top: f32) -> Self {
+        let color = chart.color.as_cosmic_color(theme);
+        let gradient = || Linear::new(Point { x: 0.0, y: base }, Point { x: 0.0, y: top });
+        match &chart.fill {
+            FillStyle::Solid => Self::solid(color),
+            FillStyle::Gradient => ChartStyle {
+                line: stroke::Style::Solid(color.into()),
+                area: stroke::Style::Gradient(
+                    gradient()
+                        .add_stop(0.0, color.with_alpha(0.0).into())
+                        .add_stop(1.0, color.with_alpha(0.5).into())
+                        .into(),
+                ),
+            },
+            FillStyle::Ramp(stops) => {
+                // Each color at its own value, which depends on the scale
+                let offset = |i: usize| {
+                    let steps = stops.len().max(2) - 1;
+                    fraction(max * i as f32 / steps as f32, max, chart.logarithmic)
+                };
+                let ramp = |paint: fn(CosmicColor) -> CosmicColor| {
+                    let linear = stops
+                        .iter()
+                        .enumerate()
+                        .fold(gradient(), |linear, (i, stop)| {
+                            linear.add_stop(offset(i), paint(stop.as_cosmic_color(theme)).into())
+                        });
+                    stroke::Style::Gradient(linear.into())
+                };
+                ChartStyle {
+                    line: ramp(|color| color),
+                    area: ramp(|color| color.with_alpha(0.5)),
+                }
+            }
+        }
+    }
+
+    /// Fills the area under a chart
+    fn fill_area(&self, frame: &mut Frame, path: &Path) {
+        frame.fill(
+            path,
+            Fill {
+                style: self.area.clone(),
+                ..Default::default()
+            },
+        );
+    }
+
+    fn stroke_line(&self, frame: &mut Frame, path: &Path) {
+        frame.stroke(
+            path,
+            Stroke {
+                style: self.line.clone(),
+                width: 1.0,
+                ..Default::default()
+            },
+        );
+    }
+}
+
+/// Draws only inside `region`, with the coordinates of the whole frame
+fn clipped(frame: &mut Frame, region: Rectangle, draw: impl FnOnce(&mut Frame)) {
+    frame.with_clip(region, |frame| {
+        frame.translate(Vector::new(-region.x, -region.y));
+        draw(frame);
+    });
+}
 
 #[derive(Debug)]
 pub struct HistoryChart<'a, T = u64> {
     history: &'a History<T>,
     max: T,
     color: Color,
+    logarithmic: bool,
+    peak_marker: bool,
+    thresholds: Thresholds,
+    fill: FillStyle,
 }
 
 impl<'a> HistoryChart<'a> {
@@ -29,6 +279,38 @@ impl<'a, T> HistoryChart<'a, T> {
             history,
             max,
             color,
+            logarithmic: false,
+            peak_marker: false,
+            thresholds: Thresholds::default(),
+            fill: FillStyle::default(),
+        }
+    }
+
+    /// Height of each threshold as a fraction of the chart height, lowest first, with its color
+    fn threshold_fractions(&self, max: f32, theme: &Theme) -> Vec<(f32, CosmicColor)> {
+        if max <= 0.0 {
+            return Vec::new();
+        }
+        let mut fractions: Vec<_> = self
+            .thresholds
+            .levels()
+            .map(|threshold| {
+                (
+                    fraction(max * threshold.percentage / 100.0, max, self.logarithmic),
+                    threshold.color.as_cosmic_color(theme),
+                )
+            })
+            .collect();
+        fractions.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        fractions
+    }
+
+    /// Color of the chart at the peak, from its thresholds or its ramp
+    fn peak_color(&self, value: f32, max: f32, theme: &Theme) -> CosmicColor {
+        let percentage = if max > 0.0 { value / max * 100.0 } else { 0.0 };
+        match self.thresholds.reached(percentage) {
+            Some(color) => color.as_cosmic_color(theme),
+            None => self.fill.color_at(percentage, &self.color, theme),
         }
     }
 }
@@ -60,15 +342,9 @@ macro_rules! impl_program_history_chart {
             ) -> Vec<Geometry<Renderer>> {
                 let mut fill = Frame::new(renderer, bounds.size());
                 let mut line = Frame::new(renderer, bounds.size());
-                let color = self.color.as_cosmic_color(theme);
 
                 let mut path_builder = path::Builder::new();
                 let x_step = bounds.width / (self.history.len() - 1) as f32;
-                let y_step = if self.max as f32 != 0.0 {
-                     bounds.height / self.max as f32
-                } else {
-                    1.0
-                };
                 path_builder.move_to(Point {
                     x: 0.0,
                     y: bounds.height,
@@ -76,7 +352,8 @@ macro_rules! impl_program_history_chart {
 
                 for (i, j) in self.history.iter().enumerate() {
                     let x = i as f32 * x_step;
-                    let y = bounds.height - *j as f32 * y_step;
+                    let y = bounds.height
+                        - bounds.height * fraction(*j as f32, self.max as f32, self.logarithmic);
                     path_builder.line_to(Point{x,y});
                 }
 
@@ -86,21 +363,32 @@ macro_rules! impl_program_history_chart {
                 });
 
                 let path = path_builder.build();
-                fill.fill(
-                    &path,
-                    Fill {
-                        style: stroke::Style::Solid(color.with_alpha(0.5).into()),
-                        ..Default::default()
-                    },
-                );
-                line.stroke(
-                    &path,
-                    Stroke {
-                        style: stroke::Style::Solid(color.into()),
-                        width: 1.0,
-                        ..Default::default()
-                    },
-                );
+                let style = ChartStyle::new(self, self.max as f32, theme, bounds.height, 0.0);
+                style.fill_area(&mut fill, &path);
+                style.stroke_line(&mut line, &path);
+                // The part of the chart above each threshold, in its color
+                for (threshold, color) in self.threshold_fractions(self.max as f32, theme) {
+                    let above = Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        width: bounds.width,
+                        height: bounds.height * (1.0 - threshold),
+                    };
+                    let style = ChartStyle::solid(color);
+                    clipped(&mut fill, above, |fill| style.fill_area(fill, &path));
+                    clipped(&mut line, above, |line| style.stroke_line(line, &path));
+                }
+                if self.peak_marker
+                    && let Some((i, value)) = peak(self.history.iter().map(|value| *value as f32))
+                {
+                    let position = Point {
+                        x: i as f32 * x_step,
+                        y: bounds.height
+                            - bounds.height * fraction(value, self.max as f32, self.logarithmic),
+                    };
+                    let color = self.peak_color(value, self.max as f32, theme);
+                    draw_peak_marker(&mut line, position, color);
+                }
                 vec![fill.into_geometry(),line.into_geometry()]
             }
         })*
@@ -112,6 +400,7 @@ impl_program_history_chart!(u64, f32);
 #[derive(Debug)]
 pub struct SimpleHistoryChart<'a, T = u64> {
     history: HistoryChart<'a, T>,
+    overlay: Overlay<'a>,
 }
 
 macro_rules! impl_program_simple_history_chart {
@@ -135,6 +424,7 @@ macro_rules! impl_program_simple_history_chart {
                     cursor: mouse::Cursor,
                 ) -> Vec<Geometry<Renderer>> {
                     let mut geometries = Background.draw(state, renderer, theme, bounds, cursor);
+                    geometries.push(self.overlay.grid(renderer, theme, bounds, false));
                     geometries.extend(self.history.draw(
                         state,
                         renderer,
@@ -142,6 +432,7 @@ macro_rules! impl_program_simple_history_chart {
                         bounds,
                         cursor,
                     ));
+                    geometries.push(self.overlay.labels(renderer, theme, bounds));
                     geometries
                 }
             }
@@ -158,24 +449,57 @@ impl<'a> SimpleHistoryChart<'a> {
             color,
         )
     }
+
+    /// Scaled to `max` when there is one, see [`Scale::max`]
+    pub fn scaled(history: &'a History, color: Color, max: Option<u64>) -> SimpleHistoryChart<'a> {
+        match max {
+            Some(max) => SimpleHistoryChart::new(history, max, color),
+            None => SimpleHistoryChart::auto_max(history, color),
+        }
+    }
+
+    /// Labels the top of the scale, if enabled in the [`Decorations`]
+    pub fn max_label(mut self, format: impl Fn(u64) -> String) -> Self {
+        self.overlay.top_label = Some(format(self.history.max));
+        self
+    }
 }
 
 impl<'a, T> SimpleHistoryChart<'a, T> {
     pub fn new(history: &'a History<T>, max: T, color: Color) -> SimpleHistoryChart<'a, T> {
         SimpleHistoryChart {
-            history: HistoryChart {
-                history,
-                max,
-                color,
-            },
+            history: HistoryChart::new(history, max, color),
+            overlay: Overlay::default(),
         }
     }
+
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.history.logarithmic = logarithmic;
+        self
+    }
+
+    pub fn thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.history.thresholds = thresholds;
+        self
+    }
+
+    pub fn fill(mut self, fill: FillStyle) -> Self {
+        self.history.fill = fill;
+        self
+    }
+
+    pub fn decorated(mut self, decorations: &'a Decorations) -> Self {
+        self.overlay.decorations = Some(decorations);
+        self.history.peak_marker = self.overlay.peak_marker();
+        self
+    }
 }
 
 #[derive(Debug)]
 pub struct SuperimposedHistoryChart<'a> {
     pub back: HistoryChart<'a>,
     pub front: HistoryChart<'a>,
+    overlay: Overlay<'a>,
 }
 
 impl<'a> SuperimposedHistoryChart<'a> {
@@ -189,7 +513,11 @@ impl<'a> SuperimposedHistoryChart<'a> {
     ) -> Self {
         let back = HistoryChart::new(data_back, max_back, *color_back);
         let front = HistoryChart::new(data_front, max_front, *color_front);
-        Self { back, front }
+        Self {
+            back,
+            front,
+            overlay: Overlay::default(),
+        }
     }
 
     pub fn new_linked(
@@ -201,7 +529,58 @@ impl<'a> SuperimposedHistoryChart<'a> {
         let mut back = HistoryChart::auto_max(data_back, *color_back);
         let mut front = HistoryChart::auto_max(data_front, *color_front);
         HistoryChart::link_max(&mut front, &mut back);
-        Self { back, front }
+        Self {
+            back,
+            front,
+            overlay: Overlay::default(),
+        }
+    }
+
+    /// Scaled to `max` when there is one, see [`Scale::max`]
+    pub fn scaled(
+        data_front: &'a History<u64>,
+        color_front: &Color,
+        data_back: &'a History<u64>,
+        color_back: &Color,
+        max: Option<u64>,
+    ) -> Self {
+        match max {
+            Some(max) => Self::new(data_front, max, color_front, data_back, max, color_back),
+            None => Self::new_linked(data_front, color_front, data_back, color_back),
+        }
+    }
+
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.back.logarithmic = logarithmic;
+        self.front.logarithmic = logarithmic;
+        self
+    }
+
+    /// Both charts change color above the same percentage of their own scale
+    pub fn thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.back.thresholds = thresholds;
+        self.front.thresholds = thresholds;
+        self
+    }
+
+    /// A ramp spans the scale of each chart
+    pub fn fill(mut self, fill: FillStyle) -> Self {
+        self.back.fill = fill.clone();
+        self.front.fill = fill;
+        self
+    }
+
+    pub fn decorated(mut self, decorations: &'a Decorations) -> Self {
+        self.overlay.decorations = Some(decorations);
+        self.back.peak_marker = self.overlay.peak_marker();
+        self.front.peak_marker = self.overlay.peak_marker();
+        self
+    }
+
+    /// Labels the top of the highest scale, if enabled in the [`Decorations`]
+    pub fn max_label(mut self, format: impl Fn(u64) -> String) -> Self {
+        self.overlay.top_label = Some(format(self.front.max.max(self.back.max)));
+        self
     }
 }
 
@@ -223,9 +602,164 @@ impl Program<Message, Theme, Renderer> for SuperimposedHistoryChart<'_> {
         cursor: mouse::Cursor,
     ) -> Vec<Geometry<Renderer>> {
         let mut geometries = Background.draw(state, renderer, theme, bounds, cursor);
+        geometries.push(self.overlay.grid(renderer, theme, bounds, false));
         let back = self.back.draw(state, renderer, theme, bounds, cursor);
         let front = self.front.draw(state, renderer, theme, bounds, cursor);
         geometries.extend(back.into_iter().zip(front).flat_map(|(f, b)| [f, b]));
+        geometries.push(self.overlay.labels(renderer, theme, bounds));
+        geometries
+    }
+}
+
+/// `front` grows up and `back` grows down from the middle, so a small front isn't hidden by a large back
+#[derive(Debug)]
+pub struct MirroredHistoryChart<'a> {
+    pub back: HistoryChart<'a>,
+    pub front: HistoryChart<'a>,
+    overlay: Overlay<'a>,
+}
+
+impl<'a> MirroredHistoryChart<'a> {
+    /// Both halves are scaled to their own highest sample, or to the highest of both when `linked`
+    pub fn new(
+        data_front: &'a History,
+        color_front: &Color,
+        data_back: &'a History,
+        color_back: &Color,
+        linked: bool,
+    ) -> Self {
+        let mut back = HistoryChart::auto_max(data_back, *color_back);
+        let mut front = HistoryChart::auto_max(data_front, *color_front);
+        if linked {
+            HistoryChart::link_max(&mut front, &mut back);
+        }
+        Self {
+            back,
+            front,
+            overlay: Overlay::default(),
+        }
+    }
+
+    /// Both halves are scaled to `max` when there is one, see [`Scale::max`]
+    pub fn scaled(
+        data_front: &'a History,
+        color_front: &Color,
+        data_back: &'a History,
+        color_back: &Color,
+        linked: bool,
+        max: Option<u64>,
+    ) -> Self {
+        let Some(max) = max else {
+            return Self::new(data_front, color_front, data_back, color_back, linked);
+        };
+        let back = HistoryChart::new(data_back, max, *color_back);
+        let front = HistoryChart::new(data_front, max, *color_front);
+        Self {
+            back,
+            front,
+            overlay: Overlay::default(),
+        }
+    }
+
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.back.logarithmic = logarithmic;
+        self.front.logarithmic = logarithmic;
+        self
+    }
+
+    /// Both halves fade out and ramp from the middle
+    pub fn fill(mut self, fill: FillStyle) -> Self {
+        self.back.fill = fill.clone();
+        self.front.fill = fill;
+        self
+    }
+
+    pub fn decorated(mut self, decorations: &'a Decorations) -> Self {
+        self.overlay.decorations = Some(decorations);
+        self.back.peak_marker = self.overlay.peak_marker();
+        self.front.peak_marker = self.overlay.peak_marker();
+        self
+    }
+
+    /// Labels the top of both halves, in the top and bottom left corners, if enabled in the [`Decorations`]
+    pub fn max_label(mut self, format: impl Fn(u64) -> String) -> Self {
+        self.overlay.top_label = Some(format(self.front.max));
+        self.overlay.bottom_label = Some(format(self.back.max));
+        self
+    }
+}
+
+impl<'a> From<MirroredHistoryChart<'a>> for Element<'a, Message> {
+    fn from(value: MirroredHistoryChart<'a>) -> Self {
+        Canvas::new(value).into()
+    }
+}
+
+impl HistoryChart<'_> {
+    /// Draws the chart from the middle of the bounds, upwards or downwards
+    #[allow(clippy::cast_precision_loss)]
+    fn draw_half(&self, frame: &mut Frame, theme: &Theme, bounds: Rectangle, upwards: bool) {
+        let middle = bounds.height / 2.0;
+        let direction = if upwards { -1.0 } else { 1.0 };
+        let x_step = bounds.width / (self.history.len().max(2) - 1) as f32;
+
+        let mut path_builder = path::Builder::new();
+        path_builder.move_to(Point { x: 0.0, y: middle });
+        for (i, value) in self.history.iter().enumerate() {
+            path_builder.line_to(Point {
+                x: i as f32 * x_step,
+                y: middle
+                    + direction
+                        * middle
+                        * fraction(*value as f32, self.max as f32, self.logarithmic),
+            });
+        }
+        path_builder.line_to(Point {
+            x: bounds.width,
+            y: middle,
+        });
+        let path = path_builder.build();
+
+        let style = ChartStyle::new(
+            self,
+            self.max as f32,
+            theme,
+            middle,
+            middle + direction * middle,
+        );
+        style.fill_area(frame, &path);
+        style.stroke_line(frame, &path);
+        if self.peak_marker
+            && let Some((i, value)) = peak(self.history.iter().map(|value| *value as f32))
+        {
+            let position = Point {
+                x: i as f32 * x_step,
+                y: middle + direction * middle * fraction(value, self.max as f32, self.logarithmic),
+            };
+            let color = self.peak_color(value, self.max as f32, theme);
+            draw_peak_marker(frame, position, color);
+        }
+    }
+}
+
+impl Program<Message, Theme, Renderer> for MirroredHistoryChart<'_> {
+    type State = ();
+
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        let mut geometries = Background.draw(state, renderer, theme, bounds, cursor);
+        geometries.push(self.overlay.grid(renderer, theme, bounds, true));
+        let mut frame = Frame::new(renderer, bounds.size());
+        self.front.draw_half(&mut frame, theme, bounds, true);
+        self.back.draw_half(&mut frame, theme, bounds, false);
+        geometries.push(frame.into_geometry());
+        geometries.push(self.overlay.labels(renderer, theme, bounds));
         geometries
     }
 }
@@ -267,3 +801,121 @@ impl Program<Message, Theme, Renderer> for Background {
         vec![frame.into_geometry()]
     }
 }
+
+/// Histories drawn on top of each other, the first one at the bottom
+#[derive(Debug)]
+pub struct StackedHistoryChart<'a> {
+    layers: Vec<(&'a History, Color)>,
+    max: u64,
+    overlay: Overlay<'a>,
+}
+
+impl<'a> StackedHistoryChart<'a> {
+    pub fn new(layers: impl IntoIterator<Item = (&'a History, Color)>, max: u64) -> Self {
+        Self {
+            layers: layers.into_iter().collect(),
+            max,
+            overlay: Overlay::default(),
+        }
+    }
+
+    pub fn decorated(mut self, decorations: &'a Decorations) -> Self {
+        self.overlay.decorations = Some(decorations);
+        self
+    }
+}
+
+impl<'a> From<StackedHistoryChart<'a>> for Element<'a, Message> {
+    fn from(value: StackedHistoryChart<'a>) -> Self {
+        Canvas::new(value).into()
+    }
+}
+
+impl Program<Message, Theme, Renderer> for StackedHistoryChart<'_> {
+    type State = ();
+
+    #[allow(clippy::cast_precision_loss)]
+    fn draw(
+        &self,
+        state: &Self::State,
+        renderer: &Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> Vec<Geometry<Renderer>> {
+        let mut geometries = Background.draw(state, renderer, theme, bounds, cursor);
+        geometries.push(self.overlay.grid(renderer, theme, bounds, false));
+        let len = self
+            .layers
+            .iter()
+            .map(|(history, _)| history.len())
+            .min()
+            .unwrap_or(0);
+        if len < 2 {
+            return geometries;
+        }
+
+        let mut frame = Frame::new(renderer, bounds.size());
+        let x_step = bounds.width / (len - 1) as f32;
+        let y_step = if self.max != 0 {
+            bounds.height / self.max as f32
+        } else {
+            1.0
+        };
+        // Older samples may add up to more than `max`, e.g. CPU ticks over a longer interval
+        let point = |i: usize, value: u64| Point {
+            x: i as f32 * x_step,
+            y: bounds.height - (value as f32 * y_step).min(bounds.height),
+        };
+
+        let mut below = vec![0; len];
+        for (history, color) in &self.layers {
+            let above: Vec<u64> = below
+                .iter()
+                .zip(history.iter())
+                .map(|(below, value)| below + value)
+                .collect();
+            let color = color.as_cosmic_color(theme);
+
+            let mut area = path::Builder::new();
+            let mut line = path::Builder::new();
+            area.move_to(point(0, below[0]));
+            line.move_to(point(0, above[0]));
+            for (i, value) in above.iter().enumerate() {
+                area.line_to(point(i, *value));
+                line.line_to(point(i, *value));
+            }
+            for (i, value) in below.iter().enumerate().rev() {
+                area.line_to(point(i, *value));
+            }
+            area.close();
+
+            frame.fill(
+                &area.build(),
+                Fill {
+                    style: stroke::Style::Solid(color.with_alpha(0.5).into()),
+                    ..Default::default()
+                },
+            );
+            frame.stroke(
+                &line.build(),
+                Stroke {
+                    style: stroke::Style::Solid(color.into()),
+                    width: 1.0,
+                    ..Default::default()
+                },
+            );
+            below = above;
+        }
+        // The peak of the sum, in the color of the top layer
+        if self.overlay.peak_marker()
+            && let Some((_, color)) = self.layers.last()
+            && let Some((i, _)) = peak(below.iter().map(|value| *value as f32))
+        {
+            draw_peak_marker(&mut frame, point(i, below[i]), color.as_cosmic_color(theme));
+        }
+        geometries.push(frame.into_geometry());
+        geometries.push(self.overlay.labels(renderer, theme, bounds));
+        geometries
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use super::procfs::Counter;
+
+/// Socket statistics, with the counters being the amount between refreshes
+#[derive(Clone, Copy, Default)]
+pub struct SocketData {
+    pub established: u64,
+    pub listening: u64,
+    /// TCP sockets in use, in any state
+    pub tcp_in_use: u64,
+    pub retransmits: u64,
+    pub udp_errors: u64,
+    /// connections dropped because the listen queue was full
+    pub listen_drops: u64,
+}
+
+#[derive(Default)]
+pub struct SocketStats {
+    retransmits: Counter,
+    udp_errors: Counter,
+    listen_drops: Counter,
+    pub data: SocketData,
+}
+
+impl SocketStats {
+    pub fn refresh(&mut self) {
+        let snmp = read_snmp("/proc/net/snmp");
+        let netstat = read_snmp("/proc/net/netstat");
+        let read = |key: &str| snmp.get(key).copied().unwrap_or_default();
+        self.data = SocketData {
+            established: read("Tcp.CurrEstab"),
+            listening: count_listening("/proc/net/tcp") + count_listening("/proc/net/tcp6"),
+            tcp_in_use: read_sockstat_in_use("/proc/net/sockstat", "TCP:")
+                + read_sockstat_in_use("/proc/net/sockstat6", "TCP6:"),
+            retransmits: self.retransmits.delta(read("Tcp.RetransSegs")),
+            udp_errors: self
+                .udp_errors
+                .delta(read("Udp.InErrors") + read("Udp.SndbufErrors")),
+            listen_drops: self.listen_drops.delta(
+                netstat
+                    .get("TcpExt.ListenDrops")
+                    .copied()
+                    .unwrap_or_default(),
+            ),
+        };
+    }
+}
+
+/// Parses files where each header line is followed by a line of values, with the same prefix, e.g.
+/// ```text
+/// Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens
+/// Tcp: 1 200 120000 -1 42
+/// ```
+/// into keys like `Tcp.ActiveOpens`. Negative values are skipped.
+fn read_snmp(path: &str) -> HashMap<String, u64> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    let mut table = HashMap::new();
+    let mut lines = content.lines();
+    while let (Some(header), Some(values)) = (lines.next(), lines.next()) {
+        let (Some((prefix, names)), Some((_, values))) =
+            (header.split_once(':'), values.split_once(':'))
+        else {
+            continue;
+        };
+        for (name, value) in names.split_whitespace().zip(values.split_whitespace()) {
+            if let Ok(value) = value.parse() {
+                table.insert(format!("{prefix}.{name}"), value);
+            }
+        }
+    }
+    table
+}
+
+/// Counts the sockets in the `LISTEN` state (`0A`) of `/proc/net/tcp` or `/proc/net/tcp6`
+fn count_listening(path: &str) -> u64 {
+    std::fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .skip(1)
+                .filter(|line| line.split_whitespace().nth(3) == Some("0A"))
+                .count() as u64
+        })
+        .unwrap_or_default()
+}
+
+/// Reads the `inuse` value of lines like `TCP: inuse 5 orphan 0 tw 0 alloc 7 mem 1`
+fn read_sockstat_in_use(path: &str, prefix: &str) -> u64 {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| {
+            let line = content.lines().find(|line| line.starts_with(prefix))?;
+            let mut fields = line
+                .split_whitespace()
+                .skip_while(|field| *field != "inuse");
+            fields.nth(1)?.parse().ok()
+        })
+        .unwrap_or_default()
+}
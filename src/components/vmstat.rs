@@ -0,0 +1,36 @@
+use super::procfs::{Counter, page_size, read_table};
+
+/// Paging activity between refreshes, from `/proc/vmstat`
+#[derive(Clone, Copy, Default)]
+pub struct PagingData {
+    /// bytes read from swap
+    pub swap_in: u64,
+    /// bytes written to swap
+    pub swap_out: u64,
+    /// page faults that needed to read from disk
+    pub major_faults: u64,
+    /// processes killed by the kernel when out of memory
+    pub oom_kills: u64,
+}
+
+#[derive(Default)]
+pub struct VmStat {
+    swap_in: Counter,
+    swap_out: Counter,
+    major_faults: Counter,
+    oom_kills: Counter,
+    pub data: PagingData,
+}
+
+impl VmStat {
+    pub fn refresh(&mut self) {
+        let table = read_table("/proc/vmstat");
+        let read = |key: &str| table.get(key).copied().unwrap_or_default();
+        self.data = PagingData {
+            swap_in: self.swap_in.delta(read("pswpin")) * page_size(),
+            swap_out: self.swap_out.delta(read("pswpout")) * page_size(),
+            major_faults: self.major_faults.delta(read("pgmajfault")),
+            oom_kills: self.oom_kills.delta(read("oom_kill")),
+        };
+    }
+}
@@ -0,0 +1,429 @@
+use std::{
+    io,
+    os::fd::{AsRawFd, FromRawFd, OwnedFd},
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Sender},
+    },
+};
+
+/// The link quality in `/proc/net/wireless` is out of the `max_qual` of the driver's wireless extensions range,
+/// which cfg80211 sets to 70 for every driver built on it, i.e. all current ones (see `net/wireless/wext-compat.c`)
+const MAX_QUALITY: f32 = 70.0;
+
+#[derive(Clone, Debug, Default)]
+pub struct WifiData {
+    pub interface: String,
+    /// link quality, in percentage
+    pub quality: u64,
+    /// signal level, in dBm
+    pub signal: i64,
+    /// in kbit/s
+    pub tx_bitrate: u64,
+    /// in kbit/s
+    pub rx_bitrate: u64,
+}
+
+impl WifiData {
+    /// Reads the first wireless interface, if any is connected, with the bitrates `bitrates` last read for it
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn read(bitrates: &mut BitrateReader) -> Option<Self> {
+        let wireless = std::fs::read_to_string("/proc/net/wireless").ok()?;
+        // Skip the 2 header lines
+        let line = wireless.lines().nth(2)?;
+        let (interface, fields) = line.split_once(':')?;
+        let mut fields = fields
+            .split_whitespace()
+            .skip(1)
+            .map(|field| field.trim_end_matches('.').parse::<f32>());
+        let quality = fields.next()?.ok()?;
+        let signal = fields.next()?.ok()?;
+
+        let interface = interface.trim().to_string();
+        let (tx_bitrate, rx_bitrate) = bitrates.read(&interface);
+        Some(Self {
+            quality: (quality / MAX_QUALITY * 100.0).clamp(0.0, 100.0) as u64,
+            signal: signal as i64,
+            tx_bitrate,
+            rx_bitrate,
+            interface,
+        })
+    }
+}
+
+/// Bitrates last read for an interface, shared with the thread reading them
+type SharedBitrates = Arc<Mutex<Option<(String, u64, u64)>>>;
+
+/// Reads the tx and rx bitrates of the access point an interface is connected to, which are only exposed
+/// through nl80211, in a thread so that netlink requests never hold up the UI
+#[derive(Default)]
+pub struct BitrateReader {
+    /// asks the thread to read an interface, dropping it stops the thread
+    requests: Option<Sender<String>>,
+    latest: SharedBitrates,
+}
+
+impl BitrateReader {
+    /// Asks for the bitrates of `interface` and returns the ones read on the previous call, in kbit/s,
+    /// or 0 until they are known
+    pub fn read(&mut self, interface: &str) -> (u64, u64) {
+        let requests = self
+            .requests
+            .get_or_insert_with(|| spawn_bitrate_reader(Arc::clone(&self.latest)));
+        _ = requests.send(interface.to_string());
+        match self.latest.lock().as_deref() {
+            Ok(Some((name, tx, rx))) if name == interface => (*tx, *rx),
+            _ => (0, 0),
+        }
+    }
+}
+
+/// Keeps an nl80211 socket open between requests, and reopens it after an error
+fn spawn_bitrate_reader(latest: SharedBitrates) -> Sender<String> {
+    let (requests, received) = mpsc::channel::<String>();
+    std::thread::spawn(move || {
+        let mut nl80211 = None;
+        while let Ok(interface) = received.recv() {
+            // Only the latest request matters when they piled up
+            let interface = received.try_iter().last().unwrap_or(interface);
+            if nl80211.is_none() {
+                nl80211 = Nl80211::open().ok();
+            }
+            let bitrates = nl80211
+                .as_ref()
+                .map(|nl80211| nl80211.read_bitrates(&interface));
+            let (tx, rx) = match bitrates {
+                Some(Ok(bitrates)) => bitrates,
+                Some(Err(_)) => {
+                    nl80211 = None;
+                    (0, 0)
+                }
+                None => (0, 0),
+            };
+            let Ok(mut latest) = latest.lock() else {
+                return;
+            };
+            *latest = Some((interface, tx, rx));
+        }
+    });
+    requests
+}
+
+// Netlink, generic netlink and nl80211 constants, from `linux/netlink.h`, `linux/genetlink.h` and `linux/nl80211.h`
+const NLMSG_ERROR: u16 = 2;
+const NLMSG_DONE: u16 = 3;
+const NLM_F_REQUEST: u16 = 0x1;
+const NLM_F_MULTI: u16 = 0x2;
+const NLM_F_DUMP: u16 = 0x300;
+const GENL_ID_CTRL: u16 = 0x10;
+const CTRL_CMD_GETFAMILY: u8 = 3;
+const CTRL_ATTR_FAMILY_ID: u16 = 1;
+const CTRL_ATTR_FAMILY_NAME: u16 = 2;
+const NL80211_CMD_GET_STATION: u8 = 17;
+const NL80211_ATTR_IFINDEX: u16 = 3;
+const NL80211_ATTR_STA_INFO: u16 = 21;
+const NL80211_STA_INFO_TX_BITRATE: u16 = 8;
+const NL80211_STA_INFO_RX_BITRATE: u16 = 14;
+const NL80211_RATE_INFO_BITRATE: u16 = 1;
+const NL80211_RATE_INFO_BITRATE32: u16 = 5;
+
+const NLMSG_HEADER_LEN: usize = 16;
+const GENL_HEADER_LEN: usize = 4;
+const NLA_HEADER_LEN: usize = 4;
+/// Strips the nested and byte order flags from attribute types
+const NLA_TYPE_MASK: u16 = 0x3fff;
+
+/// Generic netlink socket, along with the id of the nl80211 family resolved when it was opened
+struct Nl80211 {
+    socket: Netlink,
+    family: u16,
+}
+
+impl Nl80211 {
+    fn open() -> io::Result<Self> {
+        let socket = Netlink::open()?;
+        let family = socket
+            .request(
+                GENL_ID_CTRL,
+                0,
+                CTRL_CMD_GETFAMILY,
+                &attribute(CTRL_ATTR_FAMILY_NAME, b"nl80211\0"),
+            )?
+            .iter()
+            .find_map(|message| find_attribute(message, CTRL_ATTR_FAMILY_ID))
+            .and_then(|id| Some(u16::from_ne_bytes(id.get(..2)?.try_into().ok()?)))
+            .ok_or(io::ErrorKind::NotFound)?;
+        Ok(Self { socket, family })
+    }
+
+    /// Tx and rx bitrates of the access point `interface` is connected to, in kbit/s, or 0 when it isn't
+    fn read_bitrates(&self, interface: &str) -> io::Result<(u64, u64)> {
+        let Some(ifindex) = std::fs::read_to_string(format!("/sys/class/net/{interface}/ifindex"))
+            .ok()
+            .and_then(|ifindex| ifindex.trim().parse::<u32>().ok())
+        else {
+            return Ok((0, 0));
+        };
+        // In managed mode, the only station is the access point
+        let stations = self.socket.request(
+            self.family,
+            NLM_F_DUMP,
+            NL80211_CMD_GET_STATION,
+            &attribute(NL80211_ATTR_IFINDEX, &ifindex.to_ne_bytes()),
+        )?;
+        let Some(info) = stations
+            .first()
+            .and_then(|station| find_attribute(station, NL80211_ATTR_STA_INFO))
+        else {
+            return Ok((0, 0));
+        };
+        let bitrate = |kind| find_attribute(info, kind).map_or(0, read_rate);
+        Ok((
+            bitrate(NL80211_STA_INFO_TX_BITRATE),
+            bitrate(NL80211_STA_INFO_RX_BITRATE),
+        ))
+    }
+}
+
+/// Bitrate in kbit/s of a `nl80211_rate_info`, which counts in units of 100 kbit/s
+fn read_rate(rate_info: &[u8]) -> u64 {
+    let bitrate = find_attribute(rate_info, NL80211_RATE_INFO_BITRATE32)
+        .and_then(|rate| {
+            Some(u64::from(u32::from_ne_bytes(
+                rate.get(..4)?.try_into().ok()?,
+            )))
+        })
+        .or_else(|| {
+            find_attribute(rate_info, NL80211_RATE_INFO_BITRATE).and_then(|rate| {
+                Some(u64::from(u16::from_ne_bytes(
+                    rate.get(..2)?.try_into().ok()?,
+                )))
+            })
+        });
+    bitrate.unwrap_or(0) * 100
+}
+
+/// A netlink attribute, padded to 4 bytes
+fn attribute(kind: u16, payload: &[u8]) -> Vec<u8> {
+    let len = NLA_HEADER_LEN + payload.len();
+    let mut attribute = Vec::with_capacity(len.next_multiple_of(4));
+    attribute.extend_from_slice(&u16::try_from(len).unwrap_or(u16::MAX).to_ne_bytes());
+    attribute.extend_from_slice(&kind.to_ne_bytes());
+    attribute.extend_from_slice(payload);
+    attribute.resize(len.next_multiple_of(4), 0);
+    attribute
+}
+
+/// Payload of the first attribute of type `kind` among consecutive `attributes`
+fn find_attribute(attributes: &[u8], kind: u16) -> Option<&[u8]> {
+    let mut rest = attributes;
+    while rest.len() >= NLA_HEADER_LEN {
+        let len = usize::from(u16::from_ne_bytes([rest[0], rest[1]]));
+        let attribute_kind = u16::from_ne_bytes([rest[2], rest[3]]) & NLA_TYPE_MASK;
+        if len < NLA_HEADER_LEN || len > rest.len() {
+            return None;
+        }
+        if attribute_kind == kind {
+            return Some(&rest[NLA_HEADER_LEN..len]);
+        }
+        rest = rest.get(len.next_multiple_of(4)..).unwrap_or_default();
+    }
+    None
+}
+
+/// Generic netlink socket, closed on drop
+struct Netlink(OwnedFd);
+
+impl Netlink {
+    #[allow(clippy::cast_possible_truncation)]
+    fn open() -> io::Result<Self> {
+        // SAFETY: plain socket creation, the descriptor is owned right away
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                libc::NETLINK_GENERIC,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: `fd` is a freshly created descriptor that nothing else owns
+        let socket = Netlink(unsafe { OwnedFd::from_raw_fd(fd) });
+
+        // The kernel answers right away, but don't wait forever on a reply that got lost
+        let timeout = libc::timeval {
+            tv_sec: 0,
+            tv_usec: 100_000,
+        };
+        // SAFETY: `timeout` outlives the call, and its size is passed along
+        let result = unsafe {
+            libc::setsockopt(
+                socket.0.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_RCVTIMEO,
+                (&raw const timeout).cast(),
+                size_of::<libc::timeval>() as libc::socklen_t,
+            )
+        };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(socket)
+    }
+
+    /// Sends a generic netlink request to the kernel, and returns the attributes of each reply
+    fn request(
+        &self,
+        family: u16,
+        flags: u16,
+        command: u8,
+        attributes: &[u8],
+    ) -> io::Result<Vec<Vec<u8>>> {
+        let len = NLMSG_HEADER_LEN + GENL_HEADER_LEN + attributes.len();
+        let mut message = Vec::with_capacity(len);
+        message.extend_from_slice(&u32::try_from(len).unwrap_or(u32::MAX).to_ne_bytes());
+        message.extend_from_slice(&family.to_ne_bytes());
+        message.extend_from_slice(&(NLM_F_REQUEST | flags).to_ne_bytes());
+        // sequence number and port id, unused with a single request at a time
+        message.extend_from_slice(&[0; 8]);
+        message.extend_from_slice(&[command, 1, 0, 0]);
+        message.extend_from_slice(attributes);
+
+        // SAFETY: the buffer is valid for its length
+        let sent = unsafe {
+            libc::send(
+                self.0.as_raw_fd(),
+                message.as_ptr().cast(),
+                message.len(),
+                0,
+            )
+        };
+        if sent < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut replies = Vec::new();
+        let mut buffer = vec![0u8; 32 * 1024];
+        loop {
+            // SAFETY: the buffer is valid for its length
+            let received = unsafe {
+                libc::recv(
+                    self.0.as_raw_fd(),
+                    buffer.as_mut_ptr().cast(),
+                    buffer.len(),
+                    0,
+                )
+            };
+            let Ok(received) = usize::try_from(received) else {
+                return Err(io::Error::last_os_error());
+            };
+
+            let mut rest = &buffer[..received];
+            while rest.len() >= NLMSG_HEADER_LEN {
+                let len = u32::from_ne_bytes(rest[..4].try_into().unwrap_or_default()) as usize;
+                let kind = u16::from_ne_bytes([rest[4], rest[5]]);
+                let flags = u16::from_ne_bytes([rest[6], rest[7]]);
+                if len < NLMSG_HEADER_LEN || len > rest.len() {
+                    return Err(io::ErrorKind::InvalidData.into());
+                }
+                match kind {
+                    NLMSG_DONE => return Ok(replies),
+                    NLMSG_ERROR => {
+                        let code = rest
+                            .get(NLMSG_HEADER_LEN..NLMSG_HEADER_LEN + 4)
+                            .map_or(0, |code| {
+                                i32::from_ne_bytes(code.try_into().unwrap_or_default())
+                            });
+                        if code != 0 {
+                            return Err(io::Error::from_raw_os_error(-code));
+                        }
+                        // Acknowledgement
+                        return Ok(replies);
+                    }
+                    _ => {
+                        if let Some(reply) = rest.get(NLMSG_HEADER_LEN + GENL_HEADER_LEN..len) {
+                            replies.push(reply.to_vec());
+                        }
+                        // Replies to a request that isn't a dump come in a single message
+                        if flags & NLM_F_MULTI == 0 {
+                            return Ok(replies);
+                        }
+                    }
+                }
+                rest = rest.get(len.next_multiple_of(4)..).unwrap_or_default();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Attribute header in native byte order, followed by `payload`
+    fn raw_attribute(kind: u16, payload: &[u8]) -> Vec<u8> {
+        let len = u16::try_from(NLA_HEADER_LEN + payload.len()).unwrap();
+        [&len.to_ne_bytes()[..], &kind.to_ne_bytes(), payload].concat()
+    }
+
+    #[test]
+    fn pads_attributes_to_4_bytes() {
+        let built = attribute(CTRL_ATTR_FAMILY_NAME, b"nl80211\0");
+        assert_eq!(built, raw_attribute(CTRL_ATTR_FAMILY_NAME, b"nl80211\0"));
+
+        let built = attribute(NL80211_ATTR_IFINDEX, &[1, 2, 3]);
+        let mut expected = raw_attribute(NL80211_ATTR_IFINDEX, &[1, 2, 3]);
+        expected.push(0);
+        assert_eq!(built, expected);
+    }
+
+    #[test]
+    fn finds_attributes_after_padded_ones() {
+        let attributes = [
+            attribute(1, &[0xaa]),
+            attribute(NL80211_ATTR_STA_INFO, &[1, 2, 3, 4]),
+        ]
+        .concat();
+        assert_eq!(find_attribute(&attributes, 1), Some(&[0xaa][..]));
+        assert_eq!(
+            find_attribute(&attributes, NL80211_ATTR_STA_INFO),
+            Some(&[1, 2, 3, 4][..])
+        );
+        assert_eq!(find_attribute(&attributes, 7), None);
+    }
+
+    #[test]
+    fn ignores_the_nested_flag_of_attributes() {
+        let nested = raw_attribute(NL80211_ATTR_STA_INFO | 0x8000, &[]);
+        assert_eq!(
+            find_attribute(&nested, NL80211_ATTR_STA_INFO),
+            Some(&[][..])
+        );
+    }
+
+    #[test]
+    fn stops_at_truncated_attributes() {
+        let mut attributes = attribute(1, &[1, 2, 3, 4]);
+        attributes.truncate(6);
+        assert_eq!(find_attribute(&attributes, 1), None);
+        // A length shorter than the header
+        assert_eq!(find_attribute(&[2, 0, 1, 0], 1), None);
+    }
+
+    #[test]
+    fn reads_rates_in_units_of_100_kbits() {
+        let legacy = attribute(NL80211_RATE_INFO_BITRATE, &540u16.to_ne_bytes());
+        assert_eq!(read_rate(&legacy), 54_000);
+
+        // The 32 bits bitrate is preferred, since the 16 bits one saturates above 6.5 Gbit/s
+        let both = [
+            attribute(NL80211_RATE_INFO_BITRATE, &u16::MAX.to_ne_bytes()),
+            attribute(NL80211_RATE_INFO_BITRATE32, &86_470u32.to_ne_bytes()),
+        ]
+        .concat();
+        assert_eq!(read_rate(&both), 8_647_000);
+
+        assert_eq!(read_rate(&[]), 0);
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::fs::read_dir;
+use std::path::Path;
+
+use super::{gpu::read_syspath, procfs::page_size};
+
+#[derive(Clone, Copy, Default)]
+pub struct CompressedData {
+    /// size of the data before compression
+    pub original: u64,
+    /// memory used to store the compressed data
+    pub compressed: u64,
+}
+
+impl CompressedData {
+    pub fn is_empty(&self) -> bool {
+        self.original == 0
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    pub fn ratio(&self) -> f32 {
+        if self.compressed == 0 {
+            0.0
+        } else {
+            self.original as f32 / self.compressed as f32
+        }
+    }
+}
+
+impl std::ops::Add for CompressedData {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            original: self.original + rhs.original,
+            compressed: self.compressed + rhs.compressed,
+        }
+    }
+}
+
+/// Swap that is stored compressed in RAM, which `sysinfo` counts as regular swap
+#[derive(Clone, Copy, Default)]
+pub struct CompressedSwap {
+    pub zram: CompressedData,
+    pub zswap: CompressedData,
+}
+
+impl CompressedSwap {
+    /// `meminfo` is the table already read from `/proc/meminfo` for this update
+    pub fn read(meminfo: &HashMap<String, u64>) -> Self {
+        Self {
+            zram: read_zram(),
+            zswap: read_zswap(meminfo),
+        }
+    }
+
+    pub fn total(&self) -> CompressedData {
+        self.zram + self.zswap
+    }
+}
+
+/// Sums `orig_data_size` and `mem_used_total` from the `mm_stat` of every zram device
+fn read_zram() -> CompressedData {
+    read_dir("/sys/block")
+        .map(|dir_entries| {
+            dir_entries
+                .filter_map(|dir_entry| {
+                    let entry = dir_entry.ok()?;
+                    if !entry.file_name().to_str()?.starts_with("zram") {
+                        return None;
+                    }
+                    let mm_stat = std::fs::read_to_string(entry.path().join("mm_stat")).ok()?;
+                    let mut fields = mm_stat.split_whitespace().map(str::parse::<u64>);
+                    let original = fields.next()?.ok()?;
+                    let _compressed_data = fields.next()?;
+                    let compressed = fields.next()?.ok()?;
+                    Some(CompressedData {
+                        original,
+                        compressed,
+                    })
+                })
+                .fold(CompressedData::default(), |acc, data| acc + data)
+        })
+        .unwrap_or_default()
+}
+
+fn read_zswap(meminfo: &HashMap<String, u64>) -> CompressedData {
+    if let (Some(compressed), Some(original)) = (meminfo.get("Zswap"), meminfo.get("Zswapped")) {
+        return CompressedData {
+            original: original * 1024,
+            compressed: compressed * 1024,
+        };
+    }
+    let enabled = std::fs::read_to_string("/sys/module/zswap/parameters/enabled")
+        .is_ok_and(|enabled| enabled.trim() == "Y");
+    if !enabled {
+        return CompressedData::default();
+    }
+
+    // Kernels older than 5.19 only have these stats in debugfs, which usually needs root
+    let debugfs = Path::new("/sys/kernel/debug/zswap");
+    CompressedData {
+        original: read_syspath(debugfs, "stored_pages").unwrap_or_default() * page_size(),
+        compressed: read_syspath(debugfs, "pool_total_size").unwrap_or_default(),
+    }
+}
@@ -11,8 +11,16 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     applet::{ID, Message},
-    color::Color,
-    components::bar::SortMethod,
+    color::{Color, FillStyle, Thresholds},
+    components::{
+        bar::SortMethod,
+        custom::CustomSource,
+        gauge::GaugeShape,
+        processes::ProcessSort,
+        psi::PsiResource,
+        rapl::PowerDomain,
+        run::{Decorations, Scale},
+    },
 };
 pub const CONFIG_VERSION: u64 = 2;
 
@@ -109,15 +117,27 @@ pub struct LayoutConfig {
     pub padding: PaddingOption,
     pub spacing: f32,
     pub inner_spacing: f32,
+    #[serde(default)]
+    pub decorations: Decorations,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 pub struct SamplingConfig {
     pub cpu: Sampling,
     pub mem: Sampling,
     pub net: Sampling,
     pub disk: Sampling,
     pub gpu: Sampling,
+    pub load: Sampling,
+    pub psi: Sampling,
+    pub kernel: Sampling,
+    pub processes: LatestSampling,
+    pub cgroup: Sampling,
+    pub wifi: Sampling,
+    pub power: Sampling,
+    pub fans: Sampling,
+    pub custom: Sampling,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -128,6 +148,13 @@ pub struct Sampling {
     pub sampling_window: usize,
 }
 
+/// Sampling of a resource that only keeps its latest sample, and so has no `sampling_window`
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LatestSampling {
+    /// amount of time (in milliseconds) between new data
+    pub update_interval: u64,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum PaddingOption {
     Suggested,
@@ -140,10 +167,31 @@ pub enum PaddingOption {
 pub enum ComponentConfig {
     Cpu(Box<[CpuView]>),
 
-    Mem(Box<[PercentView]>),
-    Net(Box<[IoView]>),
-    Disk(Box<[IoView]>),
+    Mem(Box<[MemView]>),
+    Net(Box<[NetView]>),
+    Disk(Box<[DiskView]>),
     Gpu(Box<[PercentView]>),
+    Load(Box<[LoadView]>),
+    Psi(Box<[PsiView]>),
+    Kernel(Box<[KernelView]>),
+    Processes(Box<[ProcessView]>),
+    /// A cgroup v2, with `path` relative to `/sys/fs/cgroup`
+    Cgroup {
+        path: String,
+        views: Box<[CgroupView]>,
+    },
+    Wifi(Box<[WifiView]>),
+    Power(Box<[PowerView]>),
+    Fans(Box<[FanView]>),
+    /// Values printed by a command or written to a FIFO, in `unit` and relative to `max` when set
+    Custom {
+        source: CustomSource,
+        #[serde(default)]
+        unit: String,
+        #[serde(default)]
+        max: Option<f32>,
+        views: Box<[CustomView]>,
+    },
 }
 
 pub fn config_subscription() -> Subscription<Message> {
@@ -164,9 +212,9 @@ pub fn config_subscription() -> Subscription<Message> {
     })
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
-/// Typically used for input-output pair
-pub enum IoView {
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+/// Typically used for input-output pair, with some disk specific views
+pub enum DiskView {
     #[serde(rename = "RunChart")]
     Run {
         /// The `cosmic::palette` color to represent the relevant input (e.g. input = disk read rate, net download rate)
@@ -175,6 +223,11 @@ pub enum IoView {
         /// The `cosmic::palette` color to represent the relevant output (e.g. output = disk write rate, net upload rate)
         #[serde(alias = "color_write", alias = "color_upload")]
         color_front: Color,
+        /// How the y axis is scaled, automatically by default
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
         /// The **ratio** of width to height of the graph.
         aspect_ratio: f32,
     },
@@ -184,38 +237,257 @@ pub enum IoView {
         alias = "RunChartRead",
         alias = "RunChartDownload"
     )]
-    RunBack { color: Color, aspect_ratio: f32 },
+    RunBack {
+        color: Color,
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
     /// If IO, B is the system output (e.g. output = disk write rate, net upload rate)
     #[serde(
         rename = "RunChartFront",
         alias = "RunChartWrite",
         alias = "RunChartUpload"
     )]
-    RunFront { color: Color, aspect_ratio: f32 },
+    RunFront {
+        color: Color,
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Front growing up and back growing down from the middle, each scaled to its own maximum unless `linked`
+    #[serde(rename = "RunChartMirrored")]
+    RunMirrored {
+        #[serde(alias = "color_read", alias = "color_download")]
+        color_back: Color,
+        #[serde(alias = "color_write", alias = "color_upload")]
+        color_front: Color,
+        #[serde(default)]
+        linked: bool,
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Read (back) and write (front) requests completed between samples
+    #[serde(rename = "RunChartIops")]
+    RunIops {
+        #[serde(alias = "color_read")]
+        color_back: Color,
+        #[serde(alias = "color_write")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// Average time requests took to complete
+    #[serde(rename = "RunChartLatency")]
+    RunLatency { color: Color, aspect_ratio: f32 },
+    /// Requests being processed
+    #[serde(rename = "RunChartInFlight")]
+    RunInFlight { color: Color, aspect_ratio: f32 },
+    /// Percentage of time the busiest device was processing requests
+    #[serde(rename = "RunChartUtilization")]
+    RunUtilization {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Percentage of time the busiest device was processing requests
+    #[serde(rename = "BarChartUtilization")]
+    BarUtilization {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Temperature of the hottest drive
+    #[serde(rename = "RunChartTemperature")]
+    RunTemperature {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Current rates written in the panel, with `{read}` and `{write}` replaced in `format`
+    #[serde(rename = "Text", alias = "Label")]
+    Text {
+        #[serde(default)]
+        format: Option<String>,
+        color: Color,
+    },
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+/// Same as the run charts of [`DiskView`] for download (back) and upload (front), with some network specific views
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum NetView {
+    #[serde(rename = "RunChart")]
+    Run {
+        #[serde(alias = "color_read", alias = "color_download")]
+        color_back: Color,
+        #[serde(alias = "color_write", alias = "color_upload")]
+        color_front: Color,
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(
+        rename = "RunChartBack",
+        alias = "RunChartRead",
+        alias = "RunChartDownload"
+    )]
+    RunBack {
+        color: Color,
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(
+        rename = "RunChartFront",
+        alias = "RunChartWrite",
+        alias = "RunChartUpload"
+    )]
+    RunFront {
+        color: Color,
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Front growing up and back growing down from the middle, each scaled to its own maximum unless `linked`
+    #[serde(rename = "RunChartMirrored")]
+    RunMirrored {
+        #[serde(alias = "color_read", alias = "color_download")]
+        color_back: Color,
+        #[serde(alias = "color_write", alias = "color_upload")]
+        color_front: Color,
+        #[serde(default)]
+        linked: bool,
+        #[serde(default)]
+        scale: Scale,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Packets received (back) and transmitted (front) between samples
+    #[serde(rename = "RunChartPackets")]
+    RunPackets {
+        #[serde(alias = "color_received")]
+        color_back: Color,
+        #[serde(alias = "color_transmitted")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// Packet errors (back) and drops (front) between samples
+    #[serde(rename = "RunChartErrors")]
+    RunErrors {
+        #[serde(alias = "color_errors")]
+        color_back: Color,
+        #[serde(alias = "color_drops")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// Established (back) and listening (front) TCP connections
+    #[serde(rename = "RunChartConnections")]
+    RunConnections {
+        #[serde(alias = "color_established")]
+        color_back: Color,
+        #[serde(alias = "color_listening")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// TCP segments retransmitted between samples
+    #[serde(rename = "RunChartRetransmits")]
+    RunRetransmits { color: Color, aspect_ratio: f32 },
+    /// UDP datagrams that couldn't be received or sent between samples
+    #[serde(rename = "RunChartUdpErrors")]
+    RunUdpErrors { color: Color, aspect_ratio: f32 },
+    /// Current rates written in the panel, with `{download}` and `{upload}` replaced in `format`
+    /// (or `{download_bits}` and `{upload_bits}` for bits per second)
+    #[serde(rename = "Text", alias = "Label")]
+    Text {
+        #[serde(default)]
+        format: Option<String>,
+        color: Color,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum CpuView {
     #[serde(rename = "RunChart")]
     Run {
         color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
         aspect_ratio: f32,
     },
     BarGlobal {
         color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
         aspect_ratio: f32,
     },
     BarCores {
         color: Color,
         spacing: f32,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
         #[serde(alias = "bar_aspect_ratio")]
         aspect_ratio: f32,
         #[serde(default)]
         sorting: SortMethod,
     },
+    /// Current usage written in the panel, with `{usage}` replaced in `format`
+    #[serde(rename = "Text", alias = "Label")]
+    Text {
+        #[serde(default)]
+        format: Option<String>,
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+    },
+    /// Global usage as a ring
+    #[serde(rename = "Gauge")]
+    Gauge {
+        color: Color,
+        #[serde(default)]
+        shape: GaugeShape,
+        #[serde(default)]
+        thresholds: Thresholds,
+        aspect_ratio: f32,
+    },
+    /// Time spent in user, system and I/O wait modes, stacked
+    #[serde(rename = "RunChartSplit")]
+    RunSplit {
+        color_user: Color,
+        color_system: Color,
+        color_iowait: Color,
+        aspect_ratio: f32,
+    },
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum PercentView {
     #[serde(rename = "RunChart")]
     Run {
@@ -223,6 +495,10 @@ pub enum PercentView {
         color_back: Color,
         #[serde(alias = "color_swap", alias = "color_vram")]
         color_front: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
         aspect_ratio: f32,
     },
     #[serde(
@@ -230,13 +506,27 @@ pub enum PercentView {
         alias = "RunChartRam",
         alias = "RunChartUsage"
     )]
-    RunBack { color: Color, aspect_ratio: f32 },
+    RunBack {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
     #[serde(
         rename = "RunChartFront",
         alias = "RunChartSwap",
         alias = "RunChartVram"
     )]
-    RunFront { color: Color, aspect_ratio: f32 },
+    RunFront {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
 
     #[serde(rename = "BarChart")]
     Bar {
@@ -245,12 +535,437 @@ pub enum PercentView {
         #[serde(alias = "color_swap", alias = "color_vram")]
         color_right: Color,
         spacing: f32,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
         aspect_ratio: f32,
     },
     #[serde(alias = "BarChartRam", alias = "BarChartUsage")]
-    BarLeft { color: Color, aspect_ratio: f32 },
+    BarLeft {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
     #[serde(alias = "BarChartSwap", alias = "BarChartVram")]
-    BarRight { color: Color, aspect_ratio: f32 },
+    BarRight {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Current usage written in the panel, with `{usage}`, `{vram}` and `{vram_used}` replaced in `format`
+    #[serde(rename = "Text", alias = "Label")]
+    Text {
+        #[serde(default)]
+        format: Option<String>,
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+    },
+    /// Usage as a ring, with an inner ring for the right value when `color_right` is set
+    #[serde(rename = "Gauge")]
+    Gauge {
+        #[serde(alias = "color_ram", alias = "color_usage")]
+        color_left: Color,
+        #[serde(default, alias = "color_swap", alias = "color_vram")]
+        color_right: Option<Color>,
+        #[serde(default)]
+        shape: GaugeShape,
+        #[serde(default)]
+        thresholds: Thresholds,
+        aspect_ratio: f32,
+    },
+}
+
+/// Same as [`PercentView`] for RAM (left/back) and swap (right/front), with some memory specific views
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum MemView {
+    #[serde(rename = "RunChart")]
+    Run {
+        #[serde(alias = "color_ram", alias = "color_usage")]
+        color_back: Color,
+        #[serde(alias = "color_swap", alias = "color_vram")]
+        color_front: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(
+        rename = "RunChartBack",
+        alias = "RunChartRam",
+        alias = "RunChartUsage"
+    )]
+    RunBack {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(
+        rename = "RunChartFront",
+        alias = "RunChartSwap",
+        alias = "RunChartVram"
+    )]
+    RunFront {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Available RAM, instead of the used RAM charted by `RunChartRam`
+    #[serde(rename = "RunChartAvailable")]
+    RunAvailable { color: Color, aspect_ratio: f32 },
+    /// Swap stored in zram and zswap, before (back) and after (front) compression
+    #[serde(rename = "RunChartCompressed")]
+    RunCompressed {
+        #[serde(alias = "color_original")]
+        color_back: Color,
+        #[serde(alias = "color_compressed")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// Amount swapped in (back) and out (front) between samples
+    #[serde(rename = "RunChartSwapActivity")]
+    RunSwapActivity {
+        #[serde(alias = "color_swap_in")]
+        color_back: Color,
+        #[serde(alias = "color_swap_out")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// Page faults that had to wait for the disk between samples
+    #[serde(rename = "RunChartMajorFaults")]
+    RunMajorFaults { color: Color, aspect_ratio: f32 },
+    /// Processes killed for lack of memory between samples
+    #[serde(rename = "RunChartOomKills")]
+    RunOomKills { color: Color, aspect_ratio: f32 },
+
+    #[serde(rename = "BarChart")]
+    Bar {
+        #[serde(alias = "color_ram", alias = "color_usage")]
+        color_left: Color,
+        #[serde(alias = "color_swap", alias = "color_vram")]
+        color_right: Color,
+        spacing: f32,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(alias = "BarChartRam", alias = "BarChartUsage")]
+    BarLeft {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(alias = "BarChartSwap", alias = "BarChartVram")]
+    BarRight {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Available RAM, instead of the used RAM shown by `BarChartRam`
+    #[serde(rename = "BarChartAvailable")]
+    BarAvailable { color: Color, aspect_ratio: f32 },
+    /// RAM usage from `/proc/meminfo` stacked as used, shared, buffers, cache and dirty,
+    /// with a line marking the memory that isn't available
+    #[serde(rename = "BarChartBreakdown")]
+    BarBreakdown {
+        color_used: Color,
+        color_shared: Color,
+        color_buffers: Color,
+        color_cache: Color,
+        color_dirty: Color,
+        color_available: Color,
+        aspect_ratio: f32,
+    },
+    /// History of the same RAM breakdown as `BarChartBreakdown`, stacked
+    #[serde(rename = "RunChartBreakdown")]
+    RunBreakdown {
+        color_used: Color,
+        color_shared: Color,
+        color_buffers: Color,
+        color_cache: Color,
+        color_dirty: Color,
+        aspect_ratio: f32,
+    },
+    /// Current usage written in the panel, with `{ram}`, `{ram_used}`, `{swap}` and `{swap_used}` replaced in `format`
+    #[serde(rename = "Text", alias = "Label")]
+    Text {
+        #[serde(default)]
+        format: Option<String>,
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+    },
+    /// Usage as a ring, with an inner ring for the right value when `color_right` is set
+    #[serde(rename = "Gauge")]
+    Gauge {
+        #[serde(alias = "color_ram", alias = "color_usage")]
+        color_left: Color,
+        #[serde(default, alias = "color_swap", alias = "color_vram")]
+        color_right: Option<Color>,
+        #[serde(default)]
+        shape: GaugeShape,
+        #[serde(default)]
+        thresholds: Thresholds,
+        aspect_ratio: f32,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum LoadView {
+    /// 1-minute load average, scaled against the number of cores
+    #[serde(rename = "RunChart")]
+    Run {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Runnable (back) and I/O blocked (front) process counts, scaled against the number of cores
+    #[serde(rename = "RunChartTasks")]
+    RunTasks {
+        #[serde(alias = "color_running")]
+        color_back: Color,
+        #[serde(alias = "color_blocked")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// 1, 5 and 15-minute load averages, scaled against the number of cores
+    #[serde(rename = "BarChart")]
+    Bar {
+        color_one: Color,
+        color_five: Color,
+        color_fifteen: Color,
+        spacing: f32,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum PsiView {
+    /// Time stalled between samples, with some (back) and full (front) pressure
+    #[serde(rename = "RunChart")]
+    Run {
+        resource: PsiResource,
+        #[serde(alias = "color_some")]
+        color_back: Color,
+        #[serde(alias = "color_full")]
+        color_front: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Average pressure over the last 10 seconds, with some (left) and full (right) pressure
+    #[serde(rename = "BarChart")]
+    Bar {
+        resource: PsiResource,
+        #[serde(alias = "color_some")]
+        color_left: Color,
+        #[serde(alias = "color_full")]
+        color_right: Color,
+        spacing: f32,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+}
+
+/// Kernel activity between samples
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum KernelView {
+    #[serde(rename = "RunChartContextSwitches")]
+    RunContextSwitches { color: Color, aspect_ratio: f32 },
+    #[serde(rename = "RunChartInterrupts")]
+    RunInterrupts { color: Color, aspect_ratio: f32 },
+    /// Processes and threads created
+    #[serde(rename = "RunChartForks")]
+    RunForks { color: Color, aspect_ratio: f32 },
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum ProcessView {
+    /// The `count` processes using the most of a resource, one per line
+    List {
+        sort_by: ProcessSort,
+        count: usize,
+        color: Color,
+        aspect_ratio: f32,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum CgroupView {
+    /// CPU time used, scaled against all cores
+    #[serde(rename = "RunChartCpu")]
+    RunCpu {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// Memory used, scaled against the cgroup limit or the total RAM
+    #[serde(rename = "RunChartMem")]
+    RunMem {
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(rename = "RunChartIo")]
+    RunIo {
+        #[serde(alias = "color_read")]
+        color_back: Color,
+        #[serde(alias = "color_write")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    #[serde(rename = "BarChart")]
+    Bar {
+        #[serde(alias = "color_cpu")]
+        color_left: Color,
+        #[serde(alias = "color_mem")]
+        color_right: Color,
+        spacing: f32,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum WifiView {
+    /// Link quality
+    #[serde(rename = "RunChart")]
+    Run { color: Color, aspect_ratio: f32 },
+    /// Receive (back) and transmit (front) bitrate of the link
+    #[serde(rename = "RunChartBitrate")]
+    RunBitrate {
+        #[serde(alias = "color_rx")]
+        color_back: Color,
+        #[serde(alias = "color_tx")]
+        color_front: Color,
+        aspect_ratio: f32,
+    },
+    /// Link quality
+    #[serde(rename = "BarChart")]
+    Bar { color: Color, aspect_ratio: f32 },
+}
+
+/// Power consumed by a RAPL domain
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum PowerView {
+    #[serde(rename = "RunChart")]
+    Run {
+        domain: PowerDomain,
+        color: Color,
+        aspect_ratio: f32,
+    },
+    /// Scaled to the package power limit when available
+    #[serde(rename = "BarChart")]
+    Bar {
+        domain: PowerDomain,
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+}
+
+/// Fan speeds, relative to `max_rpm` when set, otherwise to the maximum reported by the driver or the highest speed seen
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum FanView {
+    /// Speed of the fan named `fan`, as listed in the tooltips, or else of the first fan labeled `fan`
+    #[serde(rename = "RunChart")]
+    Run {
+        fan: String,
+        color: Color,
+        #[serde(default)]
+        max_rpm: Option<u64>,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    /// One bar per fan
+    #[serde(rename = "BarChart")]
+    Bar {
+        color: Color,
+        #[serde(default)]
+        max_rpm: Option<u64>,
+        spacing: f32,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+}
+
+/// Value named `key` of a `Custom` component, or its first value when `key` isn't set
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum CustomView {
+    #[serde(rename = "RunChart")]
+    Run {
+        #[serde(default)]
+        key: Option<String>,
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
+    #[serde(rename = "BarChart")]
+    Bar {
+        #[serde(default)]
+        key: Option<String>,
+        color: Color,
+        #[serde(default)]
+        thresholds: Thresholds,
+        #[serde(default)]
+        fill: FillStyle,
+        aspect_ratio: f32,
+    },
 }
 
 impl Default for Config {
@@ -277,6 +992,7 @@ impl Default for LayoutConfig {
             padding: PaddingOption::Suggested,
             spacing: 5.0,
             inner_spacing: 2.5,
+            decorations: Decorations::default(),
         }
     }
 }
@@ -304,6 +1020,41 @@ impl Default for SamplingConfig {
                 update_interval: 2000,
                 sampling_window: 30,
             },
+            load: Sampling {
+                update_interval: 2000,
+                sampling_window: 30,
+            },
+            psi: Sampling {
+                update_interval: 2000,
+                sampling_window: 30,
+            },
+            kernel: Sampling {
+                update_interval: 1000,
+                sampling_window: 60,
+            },
+            processes: LatestSampling {
+                update_interval: 2000,
+            },
+            cgroup: Sampling {
+                update_interval: 2000,
+                sampling_window: 30,
+            },
+            wifi: Sampling {
+                update_interval: 2000,
+                sampling_window: 30,
+            },
+            power: Sampling {
+                update_interval: 1000,
+                sampling_window: 60,
+            },
+            fans: Sampling {
+                update_interval: 2000,
+                sampling_window: 30,
+            },
+            custom: Sampling {
+                update_interval: 2000,
+                sampling_window: 30,
+            },
         }
     }
 }
@@ -325,7 +1076,7 @@ impl ComponentConfig {
         let color_back = Color::accent_green;
         let color_front = Color::accent_purple;
         ComponentConfig::Mem(
-            [PercentView::Run {
+            [MemView::Run {
                 color_back,
                 color_front,
                 aspect_ratio: 1.5,
@@ -336,7 +1087,7 @@ impl ComponentConfig {
 
     fn default_net() -> Self {
         ComponentConfig::Net(
-            [IoView::Run {
+            [NetView::Run {
                 color_front: Color::accent_yellow,
                 color_back: Color::accent_red,
                 aspect_ratio: 1.5,
@@ -347,7 +1098,7 @@ impl ComponentConfig {
 
     fn default_disk() -> Self {
         ComponentConfig::Disk(
-            [IoView::Run {
+            [DiskView::Run {
                 color_front: Color::accent_orange,
                 color_back: Color::accent_pink,
                 aspect_ratio: 1.5,
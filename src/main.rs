@@ -3,8 +3,28 @@
 mod applet;
 mod components {
     pub mod bar;
+    pub mod cgroup;
+    pub mod cputimes;
+    pub mod custom;
+    pub mod diskstats;
+    pub mod drives;
+    pub mod fans;
+    pub mod gauge;
     pub mod gpu;
+    pub mod hwmon;
+    pub mod interfaces;
+    pub mod kernel;
+    pub mod load;
+    pub mod meminfo;
+    pub mod processes;
+    pub mod procfs;
+    pub mod psi;
+    pub mod rapl;
     pub mod run;
+    pub mod sockets;
+    pub mod vmstat;
+    pub mod wifi;
+    pub mod zram;
 }
 mod views;
 
@@ -2,16 +2,32 @@ use crate::{
     applet::{Message, SystemMonitorApplet, base_background},
     color::Color,
     components::{
-        bar::PercentageBar,
+        bar::{PercentageBar, StackedBar},
+        cgroup::Cgroup,
+        custom::{Custom, CustomSource},
+        drives::DriveData,
+        gauge::Gauge,
         gpu::GpuData,
-        run::{SimpleHistoryChart, SuperimposedHistoryChart},
+        load::LoadData,
+        processes::{ProcessData, ProcessSort},
+        psi::PsiResource,
+        rapl::PowerDomain,
+        run::{
+            MirroredHistoryChart, Scale, SimpleHistoryChart, StackedHistoryChart,
+            SuperimposedHistoryChart,
+        },
+        zram::CompressedData,
     },
-    config::{CpuView, IoView, PaddingOption, PercentView},
+    config::{
+        CgroupView, CpuView, CustomView, DiskView, FanView, KernelView, LoadView, MemView, NetView,
+        PaddingOption, PercentView, PowerView, ProcessView, PsiView, WifiView,
+    },
+    history::History,
 };
 use cosmic::{
     Apply, Element, Renderer, Theme,
-    iced::{Alignment, Padding, Pixels, Size, padding},
-    widget::{Column, Container, Row, container},
+    iced::{Alignment, Length, Padding, Pixels, Size, padding, widget::Stack},
+    widget::{Column, Container, Row, container, text},
 };
 use sysinfo::Cpu;
 
@@ -53,10 +69,75 @@ fn format_percentage(current: u64, total: u64) -> String {
     }
 }
 
+fn format_compressed_tooltip(name: &str, data: CompressedData) -> String {
+    format!(
+        "{}: {} → {} ({:.1}x)",
+        name,
+        format_bytes(data.original),
+        format_bytes(data.compressed),
+        data.ratio()
+    )
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bitrate(kbits: u64) -> String {
+    format!("{:.1} Mbit/s", kbits as f64 / 1000.0)
+}
+
+/// Replaces each `{name}` of `format` with its value
+fn fill_format(format: &str, values: &[(&str, String)]) -> String {
+    values
+        .iter()
+        .fold(format.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// Converts an amount accumulated over `update_interval` milliseconds to an amount per second
+fn per_second(amount: u64, update_interval: u64) -> u64 {
+    amount * 1000 / update_interval.max(1)
+}
+
+/// Unlike [`per_second`], doesn't round rare events such as a single error in a 2 seconds interval down to 0
+#[allow(clippy::cast_precision_loss)]
+fn fractional_per_second(amount: u64, update_interval: u64) -> f64 {
+    amount as f64 * 1000.0 / update_interval.max(1) as f64
+}
+
+fn format_byte_rate(amount: u64, update_interval: u64) -> String {
+    format!("{}/s", format_bytes(per_second(amount, update_interval)))
+}
+
+/// Rate per second of the latest sample of an IO history, in bytes and in bits
+fn format_io_rate(history: &History, update_interval: u64) -> (String, String) {
+    let amount = history.iter().last().copied().unwrap_or(0);
+    (
+        format_byte_rate(amount, update_interval),
+        format_bitrate(per_second(amount, update_interval) * 8 / 1000),
+    )
+}
+
 pub fn format_cpu_tooltip(usage: f32) -> String {
     format!("CPU: {usage:.1}%")
 }
 
+fn format_custom_tooltip(custom: &Custom, unit: &str) -> String {
+    let values = if custom.values.is_empty() {
+        "No value read yet".to_string()
+    } else {
+        custom
+            .values
+            .iter()
+            .map(|(key, value)| format!("{key}: {value} {unit}").trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    match &custom.error {
+        Some(error) => format!("⚠ {error}\n{values}"),
+        None => values,
+    }
+}
+
 fn format_gpu_tooltip(gpu_index: usize, gpu_data: &GpuData) -> String {
     format!(
         "{}\n{}",
@@ -81,14 +162,81 @@ fn format_gpu_vram_tooltip(gpu_index: usize, gpu_data: &GpuData) -> String {
 }
 
 impl SystemMonitorApplet {
-    fn format_mem_tooltip(&self) -> String {
+    fn format_cpu_times_tooltip(&self) -> String {
+        let data = self.cpu_times.data;
         format!(
-            "{}\n{}",
-            self.format_ram_tooltip(),
-            self.format_swap_tooltip()
+            "CPU user: {}\nCPU system: {}\nCPU I/O wait: {}",
+            format_percentage(data.user, data.total),
+            format_percentage(data.system, data.total),
+            format_percentage(data.iowait, data.total)
+        )
+    }
+
+    fn format_mem_tooltip(&self) -> String {
+        self.with_top_processes(
+            format!(
+                "{}\n{}",
+                self.format_ram_tooltip(),
+                self.format_swap_tooltip()
+            ),
+            ProcessSort::Memory,
         )
     }
 
+    fn format_process_usage(&self, process: &ProcessData, sort_by: ProcessSort) -> String {
+        match sort_by {
+            ProcessSort::Cpu => format!("{:.0}%", process.cpu),
+            ProcessSort::Memory => format_bytes(process.memory),
+            ProcessSort::DiskIo => format!(
+                "{}/s",
+                format_bytes(per_second(
+                    process.disk_io,
+                    self.config.sampling.processes.update_interval
+                ))
+            ),
+        }
+    }
+
+    fn format_processes_tooltip(&self, sort_by: ProcessSort, count: usize) -> String {
+        sort_by
+            .top(&self.processes, count)
+            .into_iter()
+            .map(|process| {
+                format!(
+                    "{}: {:.1}% CPU, {}, {}/s disk",
+                    process.name,
+                    process.cpu,
+                    format_bytes(process.memory),
+                    format_bytes(per_second(
+                        process.disk_io,
+                        self.config.sampling.processes.update_interval
+                    ))
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Appends the top consumers to the tooltip, if processes are being monitored
+    fn with_top_processes(&self, tooltip: String, sort_by: ProcessSort) -> String {
+        if self.processes.is_empty() {
+            return tooltip;
+        }
+        let top = sort_by
+            .top(&self.processes, 3)
+            .into_iter()
+            .map(|process| {
+                format!(
+                    "{} {}",
+                    process.name,
+                    self.format_process_usage(process, sort_by)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{tooltip}\ntop: {top}")
+    }
+
     fn format_ram_tooltip(&self) -> String {
         let used = self.sys.used_memory();
         let total = self.sys.total_memory();
@@ -101,6 +249,30 @@ impl SystemMonitorApplet {
         )
     }
 
+    fn format_ram_available_tooltip(&self) -> String {
+        let available = self.meminfo.available;
+        let total = self.meminfo.total;
+        format!(
+            "RAM available: {} / {} ({})",
+            format_bytes(available),
+            format_bytes(total),
+            format_percentage(available, total)
+        )
+    }
+
+    fn format_meminfo_tooltip(&self) -> String {
+        let info = &self.meminfo;
+        format!(
+            "Used: {}\nShared: {}\nBuffers: {}\nCache: {}\nDirty/Writeback: {}\n{}",
+            format_bytes(info.used),
+            format_bytes(info.shared),
+            format_bytes(info.buffers),
+            format_bytes(info.cache),
+            format_bytes(info.dirty),
+            self.format_ram_available_tooltip()
+        )
+    }
+
     fn format_swap_tooltip(&self) -> String {
         let used = self.sys.used_swap();
         let total = self.sys.total_swap();
@@ -108,21 +280,105 @@ impl SystemMonitorApplet {
             "Swap: Not available".to_string()
         } else {
             let percentage = format_percentage(used, total);
-            format!(
+            let mut tooltip = format!(
                 "Swap: {} / {} ({})",
                 format_bytes(used),
                 format_bytes(total),
                 percentage
-            )
+            );
+            for (name, data) in [
+                ("zram", self.compressed_swap.zram),
+                ("zswap", self.compressed_swap.zswap),
+            ] {
+                if !data.is_empty() {
+                    tooltip.push('\n');
+                    tooltip.push_str(&format_compressed_tooltip(name, data));
+                }
+            }
+            tooltip
         }
     }
 
-    fn format_network_tooltip(&self) -> String {
+    fn format_swap_activity_tooltip(&self) -> String {
+        let interval = self.config.sampling.mem.update_interval;
+        let data = &self.vmstat.data;
+        format!(
+            "Swap in: {}/s\nSwap out: {}/s",
+            format_bytes(per_second(data.swap_in, interval)),
+            format_bytes(per_second(data.swap_out, interval))
+        )
+    }
+
+    fn format_major_faults_tooltip(&self) -> String {
+        let interval = self.config.sampling.mem.update_interval;
+        format!(
+            "Major page faults: {}/s",
+            per_second(self.vmstat.data.major_faults, interval)
+        )
+    }
+
+    fn format_oom_kills_tooltip(&self) -> String {
+        let kills: u64 = self.oom_kills.iter().sum();
         format!(
+            "OOM kills: {kills} in the last {} samples",
+            self.oom_kills.len()
+        )
+    }
+
+    fn format_compressed_swap_tooltip(&self) -> String {
+        let total = self.compressed_swap.total();
+        if total.is_empty() {
+            "Compressed swap: Not in use".to_string()
+        } else {
+            format_compressed_tooltip("Compressed swap", total)
+        }
+    }
+
+    fn format_network_tooltip(&self) -> String {
+        let tooltip = format!(
             "{}\n{}",
             self.format_network_tooltip_inner(false),
             self.format_network_tooltip_inner(true)
-        )
+        );
+        match self.format_interface_errors() {
+            Some(errors) => format!("{errors}\n{tooltip}"),
+            None => tooltip,
+        }
+    }
+
+    /// A warning line for each interface with errors or drops, so they aren't missed
+    fn format_interface_errors(&self) -> Option<String> {
+        let interval = self.config.sampling.net.update_interval;
+        let lines: Vec<_> = self
+            .interfaces
+            .data
+            .iter()
+            .filter(|(_, packets)| packets.errors > 0 || packets.drops > 0)
+            .map(|(name, packets)| {
+                format!(
+                    "⚠ {name}: {:.1} errors/s, {:.1} drops/s",
+                    fractional_per_second(packets.errors, interval),
+                    fractional_per_second(packets.drops, interval)
+                )
+            })
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    fn format_packets_tooltip(&self) -> String {
+        let interval = self.config.sampling.net.update_interval;
+        let packets = self.interfaces.total();
+        let tooltip = format!(
+            "Packets received: {}/s\nPackets transmitted: {}/s\nErrors: {:.1}/s\nDrops: {:.1}/s",
+            per_second(packets.received, interval),
+            per_second(packets.transmitted, interval),
+            fractional_per_second(packets.errors, interval),
+            fractional_per_second(packets.drops, interval)
+        );
+        match self.format_interface_errors() {
+            Some(errors) => format!("{errors}\n{tooltip}"),
+            None => tooltip,
+        }
     }
 
     fn format_network_tooltip_inner(&self, is_upload: bool) -> String {
@@ -136,12 +392,63 @@ impl SystemMonitorApplet {
         format!("{}: {}/s", direction, format_bytes(current_rate))
     }
 
-    fn format_disk_tooltip(&self) -> String {
+    fn format_sockets_tooltip(&self) -> String {
+        let interval = self.config.sampling.net.update_interval;
+        let data = &self.sockets.data;
         format!(
+            "TCP: {} established, {} listening, {} in use\nTCP retransmits: {}/s\nTCP listen drops: {}/s\nUDP errors: {}/s",
+            data.established,
+            data.listening,
+            data.tcp_in_use,
+            per_second(data.retransmits, interval),
+            per_second(data.listen_drops, interval),
+            per_second(data.udp_errors, interval)
+        )
+    }
+
+    fn format_disk_tooltip(&self) -> String {
+        let tooltip = format!(
             "{}\n{}",
             self.format_disk_tooltip_inner(false),
             self.format_disk_tooltip_inner(true)
-        )
+        );
+        match self.format_drive_warnings() {
+            Some(warnings) => format!("{warnings}\n{tooltip}"),
+            None => tooltip,
+        }
+    }
+
+    fn format_drive_warnings(&self) -> Option<String> {
+        let lines: Vec<_> = self
+            .drives
+            .iter()
+            .filter(|drive| drive.is_overheating())
+            .map(|drive| format!("⚠ {}: {:.0} °C", drive.name, drive.temperature))
+            .collect();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    fn format_drives_tooltip(&self) -> String {
+        if self.drives.is_empty() {
+            return "No drive temperature sensor found".to_string();
+        }
+        self.drives
+            .iter()
+            .map(|drive| {
+                let mut line = format!("{}: {:.1} °C", drive.name, drive.temperature);
+                if let Some(warning) = drive.warning {
+                    line += &format!(", warning at {warning:.0} °C");
+                }
+                if let Some(critical) = drive.critical {
+                    line += &format!(", critical at {critical:.0} °C");
+                }
+                if drive.is_overheating() {
+                    line.insert_str(0, "⚠ ");
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     fn format_disk_tooltip_inner(&self, is_write: bool) -> String {
@@ -155,6 +462,168 @@ impl SystemMonitorApplet {
         format!("Disk {}: {}/s", operation, format_bytes(current_rate))
     }
 
+    #[allow(clippy::cast_precision_loss)]
+    fn format_diskstats_tooltip(&self) -> String {
+        let interval = self.config.sampling.disk.update_interval;
+        let lines: Vec<_> = self
+            .diskstats
+            .data
+            .iter()
+            .map(|(name, data)| {
+                format!(
+                    "{name}: {} reads/s, {} writes/s, {:.1} ms await, {} in flight, {}% util",
+                    per_second(data.reads, interval),
+                    per_second(data.writes, interval),
+                    data.latency() as f32 / 1000.0,
+                    data.in_flight,
+                    (data.busy_time * 100 / interval.max(1)).min(100)
+                )
+            })
+            .collect();
+        if lines.is_empty() {
+            "No disk activity data".to_string()
+        } else {
+            lines.join("\n")
+        }
+    }
+
+    fn format_load_tooltip(&self) -> String {
+        let LoadData {
+            one,
+            five,
+            fifteen,
+            running,
+            blocked,
+        } = self.load;
+        format!(
+            "Load: {one:.2} {five:.2} {fifteen:.2} ({} cores)\nRunning: {running}, Blocked: {blocked}",
+            self.sys.cpus().len()
+        )
+    }
+
+    fn format_psi_tooltip(&self, resource: PsiResource) -> String {
+        format!(
+            "{}\n{}",
+            self.format_psi_some_tooltip(resource),
+            self.format_psi_full_tooltip(resource)
+        )
+    }
+
+    fn format_psi_some_tooltip(&self, resource: PsiResource) -> String {
+        let data = self.psi.data(resource);
+        format!(
+            "{} pressure (some): {:.1}%",
+            resource.name(),
+            data.some_avg10
+        )
+    }
+
+    fn format_psi_full_tooltip(&self, resource: PsiResource) -> String {
+        let data = self.psi.data(resource);
+        format!(
+            "{} pressure (full): {:.1}%",
+            resource.name(),
+            data.full_avg10
+        )
+    }
+
+    fn format_kernel_tooltip(&self) -> String {
+        let interval = self.config.sampling.kernel.update_interval;
+        let data = &self.kernel.data;
+        format!(
+            "Context switches: {}/s\nInterrupts: {}/s\nForks: {}/s",
+            per_second(data.context_switches, interval),
+            per_second(data.interrupts, interval),
+            per_second(data.forks, interval)
+        )
+    }
+
+    /// CPU time a cgroup can use between refreshes, when using all cores
+    fn cgroup_cpu_max(&self) -> u64 {
+        self.config.sampling.cgroup.update_interval * 1000 * self.sys.cpus().len().max(1) as u64
+    }
+
+    fn cgroup_memory_max(&self, cgroup: &Cgroup) -> u64 {
+        cgroup
+            .data
+            .memory_max
+            .unwrap_or(self.sys.total_memory())
+            .min(self.sys.total_memory())
+    }
+
+    fn format_cgroup_cpu_tooltip(&self, path: &str, cgroup: &Cgroup) -> String {
+        format!(
+            "{path} CPU: {}",
+            format_percentage(cgroup.data.cpu_time, self.cgroup_cpu_max())
+        )
+    }
+
+    fn format_cgroup_mem_tooltip(&self, path: &str, cgroup: &Cgroup) -> String {
+        let max = self.cgroup_memory_max(cgroup);
+        format!(
+            "{path} Mem: {} / {} ({})",
+            format_bytes(cgroup.data.memory),
+            format_bytes(max),
+            format_percentage(cgroup.data.memory, max)
+        )
+    }
+
+    fn format_cgroup_tooltip(&self, path: &str, cgroup: &Cgroup) -> String {
+        let interval = self.config.sampling.cgroup.update_interval;
+        format!(
+            "{}\n{}\n{path} Read: {}/s\n{path} Write: {}/s",
+            self.format_cgroup_cpu_tooltip(path, cgroup),
+            self.format_cgroup_mem_tooltip(path, cgroup),
+            format_bytes(per_second(cgroup.data.io_read, interval)),
+            format_bytes(per_second(cgroup.data.io_write, interval))
+        )
+    }
+
+    fn format_wifi_tooltip(&self) -> String {
+        match &self.wifi {
+            Some(wifi) => format!(
+                "{}: {}% ({} dBm)\nBitrate: ↓{} ↑{}",
+                wifi.interface,
+                wifi.quality,
+                wifi.signal,
+                format_bitrate(wifi.rx_bitrate),
+                format_bitrate(wifi.tx_bitrate)
+            ),
+            None => "Wi-Fi: Not connected".to_string(),
+        }
+    }
+
+    fn format_power_tooltip(&self) -> String {
+        let interval = self.config.sampling.power.update_interval;
+        let lines: Vec<_> = PowerDomain::ALL
+            .into_iter()
+            .filter(|domain| self.rapl.is_available(*domain))
+            .map(|domain| {
+                // microjoules per second are microwatts
+                #[allow(clippy::cast_precision_loss)]
+                let watts = per_second(self.rapl.energy(domain), interval) as f32 / 1_000_000.0;
+                format!("{}: {watts:.1} W", domain.name())
+            })
+            .collect();
+        if lines.is_empty() {
+            "Power: RAPL energy counters are not readable".to_string()
+        } else {
+            lines.join("\n")
+        }
+    }
+
+    fn format_fans_tooltip(&self) -> String {
+        if self.fans.data.is_empty() {
+            return "No fan found".to_string();
+        }
+        self.fans
+            .data
+            .iter()
+            .map(|fan| format!("{}: {} RPM", fan.name, fan.rpm))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     fn size_aspect_ratio(&self, aspect_ratio: f32) -> Size {
         let (bounds_width, bounds_height) = self.core.applet.suggested_window_size();
         let padding = self.padding();
@@ -242,7 +711,7 @@ impl SystemMonitorApplet {
     }
 
     fn single_run_view<'a, T>(
-        &self,
+        &'a self,
         content: SimpleHistoryChart<'a, T>,
         tooltip_text: String,
         aspect_ratio: f32,
@@ -250,6 +719,81 @@ impl SystemMonitorApplet {
     where
         SimpleHistoryChart<'a, T>: Into<Element<'a, Message>>,
     {
+        let content = content.decorated(&self.config.layout.decorations);
+        self.aspect_ratio_container(content, aspect_ratio)
+            .apply(|c| self.maybe_tooltip(c, tooltip_text))
+    }
+
+    fn gauge_view<'a>(
+        &self,
+        gauge: Gauge,
+        tooltip_text: String,
+        aspect_ratio: f32,
+    ) -> Element<'a, Message> {
+        self.aspect_ratio_container(gauge, aspect_ratio)
+            .apply(|c| self.maybe_tooltip(c, tooltip_text))
+    }
+
+    /// Text sized like the rest of the panel, centered in a slot as thick as the panel
+    fn text_view<'a>(
+        &self,
+        content: String,
+        color: &Color,
+        tooltip_text: String,
+    ) -> Element<'a, Message> {
+        let text_color = cosmic::iced::Color::from(color.as_cosmic_color(cosmic::theme::active()));
+        let label = self
+            .core
+            .applet
+            .text(content)
+            .class(cosmic::theme::Text::Color(text_color));
+        let size = self.size_aspect_ratio(1.0);
+        let label = if self.is_horizontal() {
+            container(label).height(size.height)
+        } else {
+            container(label).width(size.width)
+        };
+        label
+            .align_x(Alignment::Center)
+            .align_y(Alignment::Center)
+            .style(base_background)
+            .apply(|c| self.maybe_tooltip(c, tooltip_text))
+    }
+
+    /// Overlays a warning sign in the corner of a chart when a drive is too hot
+    fn with_drive_warning<'a>(
+        &self,
+        content: impl Into<Element<'a, Message>>,
+        aspect_ratio: f32,
+    ) -> Element<'a, Message> {
+        if !self.drives.iter().any(DriveData::is_overheating) {
+            return content.into();
+        }
+        let size = self.size_aspect_ratio(aspect_ratio);
+        let badge_color =
+            cosmic::iced::Color::from(Color::bright_red.as_cosmic_color(cosmic::theme::active()));
+        let badge = text("⚠")
+            .size(size.height.min(size.width) / 2.5)
+            .class(cosmic::theme::Text::Color(badge_color));
+        Stack::with_children([
+            content.into(),
+            container(badge)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .align_x(Alignment::End)
+                .align_y(Alignment::Start)
+                .into(),
+        ])
+        .into()
+    }
+
+    fn stacked_run_view<'a>(
+        &'a self,
+        content: StackedHistoryChart<'a>,
+        tooltip_text: String,
+        aspect_ratio: f32,
+    ) -> Element<'a, Message> {
+        let content = content.decorated(&self.config.layout.decorations);
         self.aspect_ratio_container(content, aspect_ratio)
             .apply(|c| self.maybe_tooltip(c, tooltip_text))
     }
@@ -260,23 +804,19 @@ impl SystemMonitorApplet {
         tooltip_text: String,
         aspect_ratio: f32,
     ) -> Element<'a, Message> {
+        let content = content.decorated(&self.config.layout.decorations);
         self.aspect_ratio_container_with_padding(content, aspect_ratio)
             .apply(|c| self.maybe_tooltip(c, tooltip_text))
     }
 
     fn single_bar_view(
         &'_ self,
-        data: u64,
-        max: u64,
-        color: &Color,
+        bar: PercentageBar,
         tooltip_text: String,
         aspect_ratio: f32,
     ) -> Element<'_, Message> {
-        self.aspect_ratio_container(
-            PercentageBar::from_pair(self.is_horizontal(), data, max, *color),
-            aspect_ratio,
-        )
-        .apply(|c| self.maybe_tooltip(c, tooltip_text))
+        self.aspect_ratio_container(bar, aspect_ratio)
+            .apply(|c| self.maybe_tooltip(c, tooltip_text))
     }
 
     fn double_bar_view<'a>(
@@ -292,30 +832,22 @@ impl SystemMonitorApplet {
             .apply(|c| self.maybe_tooltip(c, tooltip_text))
     }
 
-    pub fn cpu_bar_view(
-        &'_ self,
-        data: f32,
-        color: &Color,
-        tooltip_text: String,
-        aspect_ratio: f32,
-    ) -> Element<'_, Message> {
-        self.aspect_ratio_container(
-            PercentageBar::new(self.is_horizontal(), data, *color),
-            aspect_ratio,
-        )
-        .apply(|c| self.maybe_tooltip(c, tooltip_text))
-    }
-
     pub fn cpu_view(&'_ self, vis: &[CpuView]) -> Vec<Element<'_, Message>> {
         vis.iter()
             .map(|v| match v {
                 CpuView::BarGlobal {
                     aspect_ratio,
                     color,
-                } => self.cpu_bar_view(
-                    self.sys.global_cpu_usage(),
-                    color,
-                    format_cpu_tooltip(self.sys.global_cpu_usage()),
+                    thresholds,
+                    fill,
+                } => self.single_bar_view(
+                    PercentageBar::new(self.is_horizontal(), self.sys.global_cpu_usage(), *color)
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
+                    self.with_top_processes(
+                        format_cpu_tooltip(self.sys.global_cpu_usage()),
+                        ProcessSort::Cpu,
+                    ),
                     *aspect_ratio,
                 ),
                 CpuView::BarCores {
@@ -323,6 +855,8 @@ impl SystemMonitorApplet {
                     color,
                     spacing,
                     sorting,
+                    thresholds,
+                    fill,
                 } => {
                     let mut cpus: Vec<_> = self.sys.cpus().iter().map(Cpu::cpu_usage).collect();
                     cpus.sort_by(sorting.method());
@@ -331,9 +865,10 @@ impl SystemMonitorApplet {
                         .into_iter()
                         .enumerate()
                         .map(|(core_idx, usage)| {
-                            self.cpu_bar_view(
-                                usage,
-                                color,
+                            self.single_bar_view(
+                                PercentageBar::new(self.is_horizontal(), usage, *color)
+                                    .thresholds(*thresholds)
+                                    .fill(fill.clone()),
                                 format!("CPU{core_idx}: {usage:.1}%"),
                                 *aspect_ratio,
                             )
@@ -353,66 +888,209 @@ impl SystemMonitorApplet {
                 CpuView::Run {
                     aspect_ratio,
                     color,
+                    thresholds,
+                    fill,
                 } => self.single_run_view(
-                    SimpleHistoryChart::new(&self.global_cpu, 100.0, *color),
-                    format_cpu_tooltip(self.sys.global_cpu_usage()),
+                    SimpleHistoryChart::new(&self.global_cpu, 100.0, *color)
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
+                    self.with_top_processes(
+                        format_cpu_tooltip(self.sys.global_cpu_usage()),
+                        ProcessSort::Cpu,
+                    ),
+                    *aspect_ratio,
+                ),
+                CpuView::Gauge {
+                    color,
+                    shape,
+                    thresholds,
+                    aspect_ratio,
+                } => {
+                    let usage = self.sys.global_cpu_usage();
+                    self.gauge_view(
+                        Gauge::new(*shape, usage, thresholds.color(usage, *color)),
+                        self.with_top_processes(format_cpu_tooltip(usage), ProcessSort::Cpu),
+                        *aspect_ratio,
+                    )
+                }
+                CpuView::RunSplit {
+                    color_user,
+                    color_system,
+                    color_iowait,
+                    aspect_ratio,
+                } => self.stacked_run_view(
+                    StackedHistoryChart::new(
+                        [
+                            (&self.cpu_user, *color_user),
+                            (&self.cpu_system, *color_system),
+                            (&self.cpu_iowait, *color_iowait),
+                        ],
+                        self.cpu_times.data.total,
+                    ),
+                    self.format_cpu_times_tooltip(),
                     *aspect_ratio,
                 ),
+                CpuView::Text {
+                    format,
+                    color,
+                    thresholds,
+                } => {
+                    let usage = self.sys.global_cpu_usage();
+                    self.text_view(
+                        fill_format(
+                            format.as_deref().unwrap_or("{usage}"),
+                            &[("usage", format!("{usage:.1}%"))],
+                        ),
+                        &thresholds.color(usage, *color),
+                        self.with_top_processes(format_cpu_tooltip(usage), ProcessSort::Cpu),
+                    )
+                }
             })
             .collect::<Vec<Element<_>>>()
     }
 
-    pub fn mem_view(&'_ self, vis: &[PercentView]) -> Vec<Element<'_, Message>> {
+    pub fn mem_view(&'_ self, vis: &[MemView]) -> Vec<Element<'_, Message>> {
         vis.iter()
             .map(|v| match v {
-                PercentView::Bar {
+                MemView::Bar {
                     color_left,
                     color_right,
                     spacing,
+                    thresholds,
+                    fill,
                     aspect_ratio,
                 } => self.double_bar_view(
                     self.single_bar_view(
-                        self.sys.used_memory(),
-                        self.sys.total_memory(),
-                        color_left,
+                        PercentageBar::from_pair(
+                            self.is_horizontal(),
+                            self.sys.used_memory(),
+                            self.sys.total_memory(),
+                            *color_left,
+                        )
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
                         self.format_ram_tooltip(),
                         *aspect_ratio,
                     ),
                     self.single_bar_view(
-                        self.sys.used_swap(),
-                        self.sys.total_swap(),
-                        color_right,
+                        PercentageBar::from_pair(
+                            self.is_horizontal(),
+                            self.sys.used_swap(),
+                            self.sys.total_swap(),
+                            *color_right,
+                        )
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
                         self.format_swap_tooltip(),
                         *aspect_ratio,
                     ),
                     self.format_mem_tooltip(),
                     *spacing,
                 ),
-                PercentView::BarLeft {
+                MemView::BarLeft {
                     color,
+                    thresholds,
+                    fill,
                     aspect_ratio,
                 } => self.single_bar_view(
-                    self.sys.used_memory(),
-                    self.sys.total_memory(),
-                    color,
+                    PercentageBar::from_pair(
+                        self.is_horizontal(),
+                        self.sys.used_memory(),
+                        self.sys.total_memory(),
+                        *color,
+                    )
+                    .thresholds(*thresholds)
+                    .fill(fill.clone()),
                     self.format_ram_tooltip(),
                     *aspect_ratio,
                 ),
 
-                PercentView::BarRight {
+                MemView::BarRight {
                     color,
+                    thresholds,
+                    fill,
                     aspect_ratio,
                 } => self.single_bar_view(
-                    self.sys.used_swap(),
-                    self.sys.total_swap(),
-                    color,
+                    PercentageBar::from_pair(
+                        self.is_horizontal(),
+                        self.sys.used_swap(),
+                        self.sys.total_swap(),
+                        *color,
+                    )
+                    .thresholds(*thresholds)
+                    .fill(fill.clone()),
                     self.format_swap_tooltip(),
                     *aspect_ratio,
                 ),
-                PercentView::Run {
+                MemView::BarAvailable {
+                    color,
+                    aspect_ratio,
+                } => self.single_bar_view(
+                    PercentageBar::from_pair(
+                        self.is_horizontal(),
+                        self.meminfo.available,
+                        self.meminfo.total,
+                        *color,
+                    ),
+                    self.format_ram_available_tooltip(),
+                    *aspect_ratio,
+                ),
+                MemView::BarBreakdown {
+                    color_used,
+                    color_shared,
+                    color_buffers,
+                    color_cache,
+                    color_dirty,
+                    color_available,
+                    aspect_ratio,
+                } => {
+                    let info = &self.meminfo;
+                    StackedBar::from_pairs(
+                        self.is_horizontal(),
+                        [
+                            (info.used, *color_used),
+                            (info.shared, *color_shared),
+                            (info.buffers, *color_buffers),
+                            (info.cache, *color_cache),
+                            (info.dirty, *color_dirty),
+                        ],
+                        info.total,
+                    )
+                    .with_marker(
+                        info.total.saturating_sub(info.available),
+                        info.total,
+                        *color_available,
+                    )
+                    .apply(|bar| self.aspect_ratio_container(bar, *aspect_ratio))
+                    .apply(|c| self.maybe_tooltip(c, self.format_meminfo_tooltip()))
+                }
+                MemView::RunBreakdown {
+                    color_used,
+                    color_shared,
+                    color_buffers,
+                    color_cache,
+                    color_dirty,
+                    aspect_ratio,
+                } => self.stacked_run_view(
+                    StackedHistoryChart::new(
+                        self.ram_breakdown.iter().zip([
+                            *color_used,
+                            *color_shared,
+                            *color_buffers,
+                            *color_cache,
+                            *color_dirty,
+                        ]),
+                        self.meminfo.total,
+                    ),
+                    self.format_meminfo_tooltip(),
+                    *aspect_ratio,
+                ),
+                MemView::Run {
                     aspect_ratio,
                     color_back,
                     color_front,
+                    thresholds,
+                    fill,
                 } => self.double_run_view(
                     SuperimposedHistoryChart::new(
                         &self.swap,
@@ -421,100 +1099,479 @@ impl SystemMonitorApplet {
                         &self.ram,
                         self.sys.total_memory(),
                         color_back,
-                    ),
+                    )
+                    .thresholds(*thresholds)
+                    .fill(fill.clone()),
                     self.format_mem_tooltip(),
                     *aspect_ratio,
                 ),
-                PercentView::RunBack {
+                MemView::RunBack {
                     color,
+                    thresholds,
+                    fill,
                     aspect_ratio,
                 } => self.single_run_view(
-                    SimpleHistoryChart::new(&self.ram, self.sys.total_memory(), *color),
+                    SimpleHistoryChart::new(&self.ram, self.sys.total_memory(), *color)
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
                     self.format_ram_tooltip(),
                     *aspect_ratio,
                 ),
-                PercentView::RunFront {
+                MemView::RunFront {
                     color,
+                    thresholds,
+                    fill,
                     aspect_ratio,
                 } => self.single_run_view(
-                    SimpleHistoryChart::new(&self.swap, self.sys.total_swap(), *color),
+                    SimpleHistoryChart::new(&self.swap, self.sys.total_swap(), *color)
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
                     self.format_swap_tooltip(),
                     *aspect_ratio,
                 ),
-            })
-            .collect()
-    }
-
-    pub fn net_view(&'_ self, vis: &[IoView]) -> Vec<Element<'_, Message>> {
-        vis.iter()
-            .map(|v| match v {
-                IoView::Run {
+                MemView::RunAvailable {
+                    color,
                     aspect_ratio,
-                    color_front,
+                } => self.single_run_view(
+                    SimpleHistoryChart::new(&self.ram_available, self.meminfo.total, *color),
+                    self.format_ram_available_tooltip(),
+                    *aspect_ratio,
+                ),
+                MemView::RunCompressed {
                     color_back,
+                    color_front,
+                    aspect_ratio,
                 } => self.double_run_view(
                     SuperimposedHistoryChart::new_linked(
-                        &self.upload,
+                        &self.swap_compressed,
                         color_front,
-                        &self.download,
+                        &self.swap_original,
                         color_back,
                     ),
-                    self.format_network_tooltip(),
+                    self.format_compressed_swap_tooltip(),
                     *aspect_ratio,
                 ),
-                IoView::RunBack {
+                MemView::RunSwapActivity {
+                    color_back,
+                    color_front,
+                    aspect_ratio,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::new_linked(
+                        &self.swap_out,
+                        color_front,
+                        &self.swap_in,
+                        color_back,
+                    ),
+                    self.format_swap_activity_tooltip(),
+                    *aspect_ratio,
+                ),
+                MemView::RunMajorFaults {
                     color,
                     aspect_ratio,
                 } => self.single_run_view(
-                    SimpleHistoryChart::auto_max(&self.download, *color),
-                    self.format_swap_tooltip(),
+                    SimpleHistoryChart::auto_max(&self.major_faults, *color),
+                    self.format_major_faults_tooltip(),
                     *aspect_ratio,
                 ),
-                IoView::RunFront {
+                MemView::RunOomKills {
                     color,
                     aspect_ratio,
                 } => self.single_run_view(
-                    SimpleHistoryChart::auto_max(&self.upload, *color),
-                    self.format_swap_tooltip(),
+                    SimpleHistoryChart::auto_max(&self.oom_kills, *color),
+                    self.format_oom_kills_tooltip(),
                     *aspect_ratio,
                 ),
+                MemView::Gauge {
+                    color_left,
+                    color_right,
+                    shape,
+                    thresholds,
+                    aspect_ratio,
+                } => {
+                    let (ram_used, ram_total) = (self.sys.used_memory(), self.sys.total_memory());
+                    let (swap_used, swap_total) = (self.sys.used_swap(), self.sys.total_swap());
+                    let mut gauge = Gauge::from_pair(
+                        *shape,
+                        ram_used,
+                        ram_total,
+                        thresholds.color_pair(ram_used, ram_total, *color_left),
+                    );
+                    if let Some(color_right) = color_right {
+                        gauge = gauge.with_ring_pair(
+                            swap_used,
+                            swap_total,
+                            thresholds.color_pair(swap_used, swap_total, *color_right),
+                        );
+                    }
+                    self.gauge_view(gauge, self.format_mem_tooltip(), *aspect_ratio)
+                }
+                MemView::Text {
+                    format,
+                    color,
+                    thresholds,
+                } => {
+                    let (ram_used, ram_total) = (self.sys.used_memory(), self.sys.total_memory());
+                    let (swap_used, swap_total) = (self.sys.used_swap(), self.sys.total_swap());
+                    self.text_view(
+                        fill_format(
+                            format.as_deref().unwrap_or("{ram} {swap}"),
+                            &[
+                                ("ram_used", format_bytes(ram_used)),
+                                ("ram", format_percentage(ram_used, ram_total)),
+                                ("swap_used", format_bytes(swap_used)),
+                                ("swap", format_percentage(swap_used, swap_total)),
+                            ],
+                        ),
+                        &thresholds.color_pair(ram_used, ram_total, *color),
+                        self.format_mem_tooltip(),
+                    )
+                }
             })
             .collect()
     }
 
-    pub fn disk_view(&'_ self, vis: &[IoView]) -> Vec<Element<'_, Message>> {
+    fn net_scale_max(&self, scale: Scale) -> Option<u64> {
+        scale.max(
+            self.config.sampling.net.update_interval,
+            self.interfaces.link_speed,
+        )
+    }
+
+    /// `LinkSpeed` acts as `Auto`, since disks have no known top speed
+    fn disk_scale_max(&self, scale: Scale) -> Option<u64> {
+        scale.max(self.config.sampling.disk.update_interval, None)
+    }
+
+    pub fn net_view(&'_ self, vis: &[NetView]) -> Vec<Element<'_, Message>> {
         vis.iter()
             .map(|v| match v {
-                IoView::Run {
+                NetView::Run {
+                    aspect_ratio,
                     color_front,
                     color_back,
+                    scale,
+                    fill,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::scaled(
+                        &self.upload,
+                        color_front,
+                        &self.download,
+                        color_back,
+                        self.net_scale_max(*scale),
+                    )
+                    .logarithmic(scale.is_logarithmic())
+                    .fill(fill.clone())
+                    .max_label(|max| {
+                        format_byte_rate(max, self.config.sampling.net.update_interval)
+                    }),
+                    self.format_network_tooltip(),
+                    *aspect_ratio,
+                ),
+                NetView::RunBack {
+                    color,
+                    scale,
+                    fill,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::scaled(&self.download, *color, self.net_scale_max(*scale))
+                        .logarithmic(scale.is_logarithmic())
+                        .fill(fill.clone())
+                        .max_label(|max| {
+                            format_byte_rate(max, self.config.sampling.net.update_interval)
+                        }),
+                    self.format_network_tooltip(),
+                    *aspect_ratio,
+                ),
+                NetView::RunFront {
+                    color,
+                    scale,
+                    fill,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::scaled(&self.upload, *color, self.net_scale_max(*scale))
+                        .logarithmic(scale.is_logarithmic())
+                        .fill(fill.clone())
+                        .max_label(|max| {
+                            format_byte_rate(max, self.config.sampling.net.update_interval)
+                        }),
+                    self.format_network_tooltip(),
+                    *aspect_ratio,
+                ),
+                NetView::RunPackets {
+                    color_back,
+                    color_front,
+                    aspect_ratio,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::new_linked(
+                        &self.packets_transmitted,
+                        color_front,
+                        &self.packets_received,
+                        color_back,
+                    ),
+                    self.format_packets_tooltip(),
+                    *aspect_ratio,
+                ),
+                NetView::RunErrors {
+                    color_back,
+                    color_front,
+                    aspect_ratio,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::new_linked(
+                        &self.packet_drops,
+                        color_front,
+                        &self.packet_errors,
+                        color_back,
+                    ),
+                    self.format_packets_tooltip(),
+                    *aspect_ratio,
+                ),
+                NetView::RunConnections {
+                    color_back,
+                    color_front,
                     aspect_ratio,
                 } => self.double_run_view(
                     SuperimposedHistoryChart::new_linked(
-                        &self.disk_write,
+                        &self.tcp_listening,
                         color_front,
-                        &self.disk_read,
+                        &self.tcp_established,
                         color_back,
                     ),
-                    self.format_disk_tooltip(),
+                    self.format_sockets_tooltip(),
                     *aspect_ratio,
                 ),
-                IoView::RunBack {
+                NetView::RunRetransmits {
                     color,
                     aspect_ratio,
                 } => self.single_run_view(
-                    SimpleHistoryChart::auto_max(&self.disk_read, *color),
-                    self.format_swap_tooltip(),
+                    SimpleHistoryChart::auto_max(&self.tcp_retransmits, *color),
+                    self.format_sockets_tooltip(),
                     *aspect_ratio,
                 ),
-                IoView::RunFront {
+                NetView::RunUdpErrors {
                     color,
                     aspect_ratio,
                 } => self.single_run_view(
-                    SimpleHistoryChart::auto_max(&self.disk_write, *color),
-                    self.format_swap_tooltip(),
+                    SimpleHistoryChart::auto_max(&self.udp_errors, *color),
+                    self.format_sockets_tooltip(),
                     *aspect_ratio,
                 ),
+                NetView::RunMirrored {
+                    color_back,
+                    color_front,
+                    linked,
+                    scale,
+                    fill,
+                    aspect_ratio,
+                } => MirroredHistoryChart::scaled(
+                    &self.upload,
+                    color_front,
+                    &self.download,
+                    color_back,
+                    *linked,
+                    self.net_scale_max(*scale),
+                )
+                .logarithmic(scale.is_logarithmic())
+                .fill(fill.clone())
+                .max_label(|max| format_byte_rate(max, self.config.sampling.net.update_interval))
+                .decorated(&self.config.layout.decorations)
+                .apply(|chart| self.aspect_ratio_container(chart, *aspect_ratio))
+                .apply(|c| self.maybe_tooltip(c, self.format_network_tooltip())),
+                NetView::Text { format, color } => {
+                    let interval = self.config.sampling.net.update_interval;
+                    let (download, download_bits) = format_io_rate(&self.download, interval);
+                    let (upload, upload_bits) = format_io_rate(&self.upload, interval);
+                    self.text_view(
+                        fill_format(
+                            format.as_deref().unwrap_or("↓{download} ↑{upload}"),
+                            &[
+                                ("download_bits", download_bits),
+                                ("download", download),
+                                ("upload_bits", upload_bits),
+                                ("upload", upload),
+                            ],
+                        ),
+                        color,
+                        self.format_network_tooltip(),
+                    )
+                }
+            })
+            .collect()
+    }
+
+    pub fn disk_view(&'_ self, vis: &[DiskView]) -> Vec<Element<'_, Message>> {
+        vis.iter()
+            .map(|v| match v {
+                DiskView::Run {
+                    color_front,
+                    color_back,
+                    scale,
+                    fill,
+                    aspect_ratio,
+                } => SuperimposedHistoryChart::scaled(
+                    &self.disk_write,
+                    color_front,
+                    &self.disk_read,
+                    color_back,
+                    self.disk_scale_max(*scale),
+                )
+                .logarithmic(scale.is_logarithmic())
+                .fill(fill.clone())
+                .max_label(|max| format_byte_rate(max, self.config.sampling.disk.update_interval))
+                .decorated(&self.config.layout.decorations)
+                .apply(|chart| self.with_drive_warning(chart, *aspect_ratio))
+                .apply(|chart| self.aspect_ratio_container_with_padding(chart, *aspect_ratio))
+                .apply(|c| self.maybe_tooltip(c, self.format_disk_tooltip())),
+                DiskView::RunBack {
+                    color,
+                    scale,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    SimpleHistoryChart::scaled(&self.disk_read, *color, self.disk_scale_max(*scale))
+                        .logarithmic(scale.is_logarithmic())
+                        .fill(fill.clone())
+                        .max_label(|max| {
+                            format_byte_rate(max, self.config.sampling.disk.update_interval)
+                        })
+                        .decorated(&self.config.layout.decorations)
+                        .apply(|chart| self.with_drive_warning(chart, *aspect_ratio))
+                        .apply(|chart| self.aspect_ratio_container(chart, *aspect_ratio))
+                        .apply(|c| self.maybe_tooltip(c, self.format_disk_tooltip()))
+                }
+                DiskView::RunFront {
+                    color,
+                    scale,
+                    fill,
+                    aspect_ratio,
+                } => SimpleHistoryChart::scaled(
+                    &self.disk_write,
+                    *color,
+                    self.disk_scale_max(*scale),
+                )
+                .logarithmic(scale.is_logarithmic())
+                .fill(fill.clone())
+                .max_label(|max| format_byte_rate(max, self.config.sampling.disk.update_interval))
+                .decorated(&self.config.layout.decorations)
+                .apply(|chart| self.with_drive_warning(chart, *aspect_ratio))
+                .apply(|chart| self.aspect_ratio_container(chart, *aspect_ratio))
+                .apply(|c| self.maybe_tooltip(c, self.format_disk_tooltip())),
+                DiskView::RunIops {
+                    color_front,
+                    color_back,
+                    aspect_ratio,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::new_linked(
+                        &self.disk_write_ops,
+                        color_front,
+                        &self.disk_read_ops,
+                        color_back,
+                    ),
+                    self.format_diskstats_tooltip(),
+                    *aspect_ratio,
+                ),
+                DiskView::RunLatency {
+                    color,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::auto_max(&self.disk_latency, *color),
+                    self.format_diskstats_tooltip(),
+                    *aspect_ratio,
+                ),
+                DiskView::RunInFlight {
+                    color,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::auto_max(&self.disk_in_flight, *color),
+                    self.format_diskstats_tooltip(),
+                    *aspect_ratio,
+                ),
+                DiskView::RunUtilization {
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::new(
+                        &self.disk_busy_time,
+                        self.config.sampling.disk.update_interval,
+                        *color,
+                    )
+                    .thresholds(*thresholds)
+                    .fill(fill.clone()),
+                    self.format_diskstats_tooltip(),
+                    *aspect_ratio,
+                ),
+                DiskView::BarUtilization {
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let busy_time = self.disk_busy_time.iter().last().copied().unwrap_or(0);
+                    let interval = self.config.sampling.disk.update_interval;
+                    self.single_bar_view(
+                        PercentageBar::from_pair(self.is_horizontal(), busy_time, interval, *color)
+                            .thresholds(*thresholds)
+                            .fill(fill.clone()),
+                        self.format_diskstats_tooltip(),
+                        *aspect_ratio,
+                    )
+                }
+                DiskView::RunTemperature {
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let max = self
+                        .drives
+                        .iter()
+                        .filter_map(|drive| drive.critical.or(drive.warning))
+                        .chain(self.disk_temperature.iter().copied())
+                        .fold(0.0, f32::max);
+                    SimpleHistoryChart::new(&self.disk_temperature, max, *color)
+                        .thresholds(*thresholds)
+                        .fill(fill.clone())
+                        .decorated(&self.config.layout.decorations)
+                        .apply(|chart| self.with_drive_warning(chart, *aspect_ratio))
+                        .apply(|chart| self.aspect_ratio_container(chart, *aspect_ratio))
+                        .apply(|c| self.maybe_tooltip(c, self.format_drives_tooltip()))
+                }
+                DiskView::RunMirrored {
+                    color_back,
+                    color_front,
+                    linked,
+                    scale,
+                    fill,
+                    aspect_ratio,
+                } => MirroredHistoryChart::scaled(
+                    &self.disk_write,
+                    color_front,
+                    &self.disk_read,
+                    color_back,
+                    *linked,
+                    self.disk_scale_max(*scale),
+                )
+                .logarithmic(scale.is_logarithmic())
+                .fill(fill.clone())
+                .max_label(|max| format_byte_rate(max, self.config.sampling.disk.update_interval))
+                .decorated(&self.config.layout.decorations)
+                .apply(|chart| self.with_drive_warning(chart, *aspect_ratio))
+                .apply(|chart| self.aspect_ratio_container(chart, *aspect_ratio))
+                .apply(|c| self.maybe_tooltip(c, self.format_disk_tooltip())),
+                DiskView::Text { format, color } => {
+                    let interval = self.config.sampling.disk.update_interval;
+                    let (read, _) = format_io_rate(&self.disk_read, interval);
+                    let (write, _) = format_io_rate(&self.disk_write, interval);
+                    self.text_view(
+                        fill_format(
+                            format.as_deref().unwrap_or("R {read} W {write}"),
+                            &[("read", read), ("write", write)],
+                        ),
+                        color,
+                        self.format_disk_tooltip(),
+                    )
+                }
             })
             .collect()
     }
@@ -531,19 +1588,31 @@ impl SystemMonitorApplet {
                             color_left,
                             color_right,
                             spacing,
+                            thresholds,
+                            fill,
                             aspect_ratio,
                         } => self.double_bar_view(
                             self.single_bar_view(
-                                data.usage,
-                                100,
-                                color_left,
+                                PercentageBar::from_pair(
+                                    self.is_horizontal(),
+                                    data.usage,
+                                    100,
+                                    *color_left,
+                                )
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
                                 format_gpu_usage_tooltip(idx, data),
                                 *aspect_ratio,
                             ),
                             self.single_bar_view(
-                                data.used_vram,
-                                data.total_vram,
-                                color_right,
+                                PercentageBar::from_pair(
+                                    self.is_horizontal(),
+                                    data.used_vram,
+                                    data.total_vram,
+                                    *color_right,
+                                )
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
                                 format_gpu_vram_tooltip(idx, data),
                                 *aspect_ratio,
                             ),
@@ -552,21 +1621,30 @@ impl SystemMonitorApplet {
                         ),
                         PercentView::BarLeft {
                             color,
+                            thresholds,
+                            fill,
                             aspect_ratio,
                         } => self.single_bar_view(
-                            data.usage,
-                            100,
-                            color,
+                            PercentageBar::from_pair(self.is_horizontal(), data.usage, 100, *color)
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
                             format_gpu_usage_tooltip(idx, data),
                             *aspect_ratio,
                         ),
                         PercentView::BarRight {
                             color,
+                            thresholds,
+                            fill,
                             aspect_ratio,
                         } => self.single_bar_view(
-                            data.used_vram,
-                            data.total_vram,
-                            color,
+                            PercentageBar::from_pair(
+                                self.is_horizontal(),
+                                data.used_vram,
+                                data.total_vram,
+                                *color,
+                            )
+                            .thresholds(*thresholds)
+                            .fill(fill.clone()),
                             format_gpu_vram_tooltip(idx, data),
                             *aspect_ratio,
                         ),
@@ -575,6 +1653,8 @@ impl SystemMonitorApplet {
                             aspect_ratio,
                             color_back,
                             color_front,
+                            thresholds,
+                            fill,
                         } => self.double_run_view(
                             SuperimposedHistoryChart::new(
                                 &self.vram[idx],
@@ -583,29 +1663,577 @@ impl SystemMonitorApplet {
                                 &self.gpu_usage[idx],
                                 100,
                                 color_back,
-                            ),
+                            )
+                            .thresholds(*thresholds)
+                            .fill(fill.clone()),
                             format_gpu_tooltip(idx, data),
                             *aspect_ratio,
                         ),
                         PercentView::RunBack {
                             color,
+                            thresholds,
+                            fill,
                             aspect_ratio,
                         } => self.single_run_view(
-                            SimpleHistoryChart::new(&self.gpu_usage[idx], 100, *color),
+                            SimpleHistoryChart::new(&self.gpu_usage[idx], 100, *color)
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
                             format_gpu_usage_tooltip(idx, data),
                             *aspect_ratio,
                         ),
                         PercentView::RunFront {
                             color,
+                            thresholds,
+                            fill,
                             aspect_ratio,
                         } => self.single_run_view(
-                            SimpleHistoryChart::new(&self.vram[idx], data.total_vram, *color),
+                            SimpleHistoryChart::new(&self.vram[idx], data.total_vram, *color)
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
                             format_gpu_usage_tooltip(idx, data),
                             *aspect_ratio,
                         ),
+                        PercentView::Gauge {
+                            color_left,
+                            color_right,
+                            shape,
+                            thresholds,
+                            aspect_ratio,
+                        } => {
+                            let mut gauge = Gauge::from_pair(
+                                *shape,
+                                data.usage,
+                                100,
+                                thresholds.color_pair(data.usage, 100, *color_left),
+                            );
+                            if let Some(color_right) = color_right {
+                                gauge = gauge.with_ring_pair(
+                                    data.used_vram,
+                                    data.total_vram,
+                                    thresholds.color_pair(
+                                        data.used_vram,
+                                        data.total_vram,
+                                        *color_right,
+                                    ),
+                                );
+                            }
+                            self.gauge_view(gauge, format_gpu_tooltip(idx, data), *aspect_ratio)
+                        }
+                        PercentView::Text {
+                            format,
+                            color,
+                            thresholds,
+                        } => self.text_view(
+                            fill_format(
+                                format.as_deref().unwrap_or("{usage} {vram}"),
+                                &[
+                                    ("usage", format!("{}%", data.usage)),
+                                    ("vram_used", format_bytes(data.used_vram)),
+                                    ("vram", format_percentage(data.used_vram, data.total_vram)),
+                                ],
+                            ),
+                            &thresholds.color_pair(data.usage, 100, *color),
+                            format_gpu_tooltip(idx, data),
+                        ),
                     })
                     .collect::<Vec<_>>()
             })
             .collect()
     }
+
+    pub fn load_view(&'_ self, vis: &[LoadView]) -> Vec<Element<'_, Message>> {
+        let cores = self.sys.cpus().len().max(1);
+        #[allow(clippy::cast_precision_loss)]
+        let cores_f32 = cores as f32;
+        vis.iter()
+            .map(|v| match v {
+                LoadView::Run {
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    // Keep the chart scaled to the core count, unless the load goes over it
+                    let max = self.load_avg.iter().copied().fold(cores_f32, f32::max);
+                    self.single_run_view(
+                        SimpleHistoryChart::new(&self.load_avg, max, *color)
+                            .thresholds(*thresholds)
+                            .fill(fill.clone()),
+                        self.format_load_tooltip(),
+                        *aspect_ratio,
+                    )
+                }
+                LoadView::RunTasks {
+                    color_back,
+                    color_front,
+                    aspect_ratio,
+                } => {
+                    let max = self
+                        .procs_running
+                        .iter()
+                        .chain(self.procs_blocked.iter())
+                        .copied()
+                        .fold(cores as u64, u64::max);
+                    self.double_run_view(
+                        SuperimposedHistoryChart::new(
+                            &self.procs_blocked,
+                            max,
+                            color_front,
+                            &self.procs_running,
+                            max,
+                            color_back,
+                        ),
+                        self.format_load_tooltip(),
+                        *aspect_ratio,
+                    )
+                }
+                LoadView::Bar {
+                    color_one,
+                    color_five,
+                    color_fifteen,
+                    spacing,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let bars: Vec<Element<_>> = [
+                        ("1m", self.load.one, color_one),
+                        ("5m", self.load.five, color_five),
+                        ("15m", self.load.fifteen, color_fifteen),
+                    ]
+                    .into_iter()
+                    .map(|(label, load, color)| {
+                        let percentage = load / cores_f32 * 100.0;
+                        self.single_bar_view(
+                            PercentageBar::new(self.is_horizontal(), percentage, *color)
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
+                            format!("Load {label}: {load:.2}"),
+                            *aspect_ratio,
+                        )
+                    })
+                    .collect();
+
+                    self.panel_collection(bars, *spacing, 0.0)
+                        .apply(container)
+                        .style(base_background)
+                        .apply(|c| self.maybe_tooltip(c, self.format_load_tooltip()))
+                }
+            })
+            .collect()
+    }
+
+    pub fn psi_view(&'_ self, vis: &[PsiView]) -> Vec<Element<'_, Message>> {
+        // The stalled time between samples can't be longer than the interval itself
+        let max = self.config.sampling.psi.update_interval * 1000;
+        vis.iter()
+            .map(|v| match v {
+                PsiView::Run {
+                    resource,
+                    color_back,
+                    color_front,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::new(
+                        &self.psi_full[resource.index()],
+                        max,
+                        color_front,
+                        &self.psi_some[resource.index()],
+                        max,
+                        color_back,
+                    )
+                    .thresholds(*thresholds)
+                    .fill(fill.clone()),
+                    self.format_psi_tooltip(*resource),
+                    *aspect_ratio,
+                ),
+                PsiView::Bar {
+                    resource,
+                    color_left,
+                    color_right,
+                    spacing,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let data = self.psi.data(*resource);
+                    self.double_bar_view(
+                        self.single_bar_view(
+                            PercentageBar::new(self.is_horizontal(), data.some_avg10, *color_left)
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
+                            self.format_psi_some_tooltip(*resource),
+                            *aspect_ratio,
+                        ),
+                        self.single_bar_view(
+                            PercentageBar::new(self.is_horizontal(), data.full_avg10, *color_right)
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
+                            self.format_psi_full_tooltip(*resource),
+                            *aspect_ratio,
+                        ),
+                        self.format_psi_tooltip(*resource),
+                        *spacing,
+                    )
+                }
+            })
+            .collect()
+    }
+
+    pub fn kernel_view(&'_ self, vis: &[KernelView]) -> Vec<Element<'_, Message>> {
+        vis.iter()
+            .map(|v| {
+                let (history, color, aspect_ratio) = match v {
+                    KernelView::RunContextSwitches {
+                        color,
+                        aspect_ratio,
+                    } => (&self.context_switches, color, aspect_ratio),
+                    KernelView::RunInterrupts {
+                        color,
+                        aspect_ratio,
+                    } => (&self.interrupts, color, aspect_ratio),
+                    KernelView::RunForks {
+                        color,
+                        aspect_ratio,
+                    } => (&self.forks, color, aspect_ratio),
+                };
+                self.single_run_view(
+                    SimpleHistoryChart::auto_max(history, *color),
+                    self.format_kernel_tooltip(),
+                    *aspect_ratio,
+                )
+            })
+            .collect()
+    }
+
+    pub fn processes_view(&'_ self, vis: &[ProcessView]) -> Vec<Element<'_, Message>> {
+        vis.iter()
+            .map(|v| match v {
+                ProcessView::List {
+                    sort_by,
+                    count,
+                    color,
+                    aspect_ratio,
+                } => {
+                    let size = self.size_aspect_ratio(*aspect_ratio);
+                    // Shrink the font so all lines fit in the panel
+                    #[allow(clippy::cast_precision_loss)]
+                    let font_size = size.height / (*count).max(1) as f32 / 1.4;
+                    let text_color =
+                        cosmic::iced::Color::from(color.as_cosmic_color(cosmic::theme::active()));
+                    let lines: Vec<Element<_>> = sort_by
+                        .top(&self.processes, *count)
+                        .into_iter()
+                        .map(|process| {
+                            text(format!(
+                                "{} {}",
+                                process.name,
+                                self.format_process_usage(process, *sort_by)
+                            ))
+                            .size(font_size)
+                            .class(cosmic::theme::Text::Color(text_color))
+                            .into()
+                        })
+                        .collect();
+                    Column::with_children(lines)
+                        .apply(|list| sized_container(list, size))
+                        .clip(true)
+                        .apply(|c| {
+                            self.maybe_tooltip(c, self.format_processes_tooltip(*sort_by, *count))
+                        })
+                }
+            })
+            .collect()
+    }
+
+    pub fn cgroup_view<'a>(&'a self, path: &str, vis: &[CgroupView]) -> Vec<Element<'a, Message>> {
+        let Some(cgroup) = self.cgroups.get(path) else {
+            return Vec::new();
+        };
+        vis.iter()
+            .map(|v| match v {
+                CgroupView::RunCpu {
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::new(&cgroup.cpu_history, self.cgroup_cpu_max(), *color)
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
+                    self.format_cgroup_cpu_tooltip(path, cgroup),
+                    *aspect_ratio,
+                ),
+                CgroupView::RunMem {
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::new(
+                        &cgroup.memory_history,
+                        self.cgroup_memory_max(cgroup),
+                        *color,
+                    )
+                    .thresholds(*thresholds)
+                    .fill(fill.clone()),
+                    self.format_cgroup_mem_tooltip(path, cgroup),
+                    *aspect_ratio,
+                ),
+                CgroupView::RunIo {
+                    color_back,
+                    color_front,
+                    aspect_ratio,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::new_linked(
+                        &cgroup.write_history,
+                        color_front,
+                        &cgroup.read_history,
+                        color_back,
+                    ),
+                    self.format_cgroup_tooltip(path, cgroup),
+                    *aspect_ratio,
+                ),
+                CgroupView::Bar {
+                    color_left,
+                    color_right,
+                    spacing,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => self.double_bar_view(
+                    self.single_bar_view(
+                        PercentageBar::from_pair(
+                            self.is_horizontal(),
+                            cgroup.data.cpu_time,
+                            self.cgroup_cpu_max(),
+                            *color_left,
+                        )
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
+                        self.format_cgroup_cpu_tooltip(path, cgroup),
+                        *aspect_ratio,
+                    ),
+                    self.single_bar_view(
+                        PercentageBar::from_pair(
+                            self.is_horizontal(),
+                            cgroup.data.memory,
+                            self.cgroup_memory_max(cgroup),
+                            *color_right,
+                        )
+                        .thresholds(*thresholds)
+                        .fill(fill.clone()),
+                        self.format_cgroup_mem_tooltip(path, cgroup),
+                        *aspect_ratio,
+                    ),
+                    self.format_cgroup_tooltip(path, cgroup),
+                    *spacing,
+                ),
+            })
+            .collect()
+    }
+
+    pub fn wifi_view(&'_ self, vis: &[WifiView]) -> Vec<Element<'_, Message>> {
+        vis.iter()
+            .map(|v| match v {
+                WifiView::Run {
+                    color,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::new(&self.wifi_quality, 100, *color),
+                    self.format_wifi_tooltip(),
+                    *aspect_ratio,
+                ),
+                WifiView::RunBitrate {
+                    color_back,
+                    color_front,
+                    aspect_ratio,
+                } => self.double_run_view(
+                    SuperimposedHistoryChart::new_linked(
+                        &self.wifi_tx_bitrate,
+                        color_front,
+                        &self.wifi_rx_bitrate,
+                        color_back,
+                    ),
+                    self.format_wifi_tooltip(),
+                    *aspect_ratio,
+                ),
+                WifiView::Bar {
+                    color,
+                    aspect_ratio,
+                } => self.single_bar_view(
+                    PercentageBar::from_pair(
+                        self.is_horizontal(),
+                        self.wifi.as_ref().map_or(0, |wifi| wifi.quality),
+                        100,
+                        *color,
+                    ),
+                    self.format_wifi_tooltip(),
+                    *aspect_ratio,
+                ),
+            })
+            .collect()
+    }
+
+    pub fn power_view(&'_ self, vis: &[PowerView]) -> Vec<Element<'_, Message>> {
+        vis.iter()
+            .map(|v| match v {
+                PowerView::Run {
+                    domain,
+                    color,
+                    aspect_ratio,
+                } => self.single_run_view(
+                    SimpleHistoryChart::auto_max(&self.power[domain.index()], *color),
+                    self.format_power_tooltip(),
+                    *aspect_ratio,
+                ),
+                PowerView::Bar {
+                    domain,
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let history = &self.power[domain.index()];
+                    let max = match (domain, self.rapl.package_limit) {
+                        // The limit is in microwatts, while the history is in microjoules per interval
+                        (PowerDomain::Package, Some(limit)) => {
+                            limit * self.config.sampling.power.update_interval / 1000
+                        }
+                        _ => history.iter().copied().max().unwrap_or(0),
+                    };
+                    let current = history.iter().last().copied().unwrap_or(0);
+                    self.single_bar_view(
+                        PercentageBar::from_pair(self.is_horizontal(), current, max, *color)
+                            .thresholds(*thresholds)
+                            .fill(fill.clone()),
+                        self.format_power_tooltip(),
+                        *aspect_ratio,
+                    )
+                }
+            })
+            .collect()
+    }
+
+    pub fn fans_view(&'_ self, vis: &[FanView]) -> Vec<Element<'_, Message>> {
+        vis.iter()
+            .map(|v| match v {
+                FanView::Run {
+                    fan,
+                    color,
+                    max_rpm,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let history = self.fans.history(fan);
+                    let data = self.fans.get(fan);
+                    let chart = SimpleHistoryChart::scaled(
+                        history,
+                        *color,
+                        data.map(|fan| fan.max_rpm(*max_rpm)),
+                    );
+                    // The fan may not have been read yet, or be missing from this machine
+                    let tooltip = if data.is_some() {
+                        self.format_fans_tooltip()
+                    } else {
+                        format!("Unknown fan: {fan}")
+                    };
+                    self.single_run_view(
+                        chart.thresholds(*thresholds).fill(fill.clone()),
+                        tooltip,
+                        *aspect_ratio,
+                    )
+                }
+                FanView::Bar {
+                    color,
+                    max_rpm,
+                    spacing,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let bars: Vec<Element<_>> = self
+                        .fans
+                        .data
+                        .iter()
+                        .map(|fan| {
+                            self.single_bar_view(
+                                PercentageBar::from_pair(
+                                    self.is_horizontal(),
+                                    fan.rpm,
+                                    fan.max_rpm(*max_rpm),
+                                    *color,
+                                )
+                                .thresholds(*thresholds)
+                                .fill(fill.clone()),
+                                format!("{}: {} RPM", fan.name, fan.rpm),
+                                *aspect_ratio,
+                            )
+                        })
+                        .collect();
+                    self.panel_collection(bars, *spacing, 0.0)
+                        .apply(container)
+                        .style(base_background)
+                        .apply(|c| self.maybe_tooltip(c, self.format_fans_tooltip()))
+                }
+            })
+            .collect()
+    }
+
+    pub fn custom_view<'a>(
+        &'a self,
+        source: &CustomSource,
+        unit: &str,
+        max: Option<f32>,
+        vis: &[CustomView],
+    ) -> Vec<Element<'a, Message>> {
+        let Some(custom) = self.customs.iter().find(|custom| custom.source == *source) else {
+            return Vec::new();
+        };
+        vis.iter()
+            .map(|v| match v {
+                CustomView::Run {
+                    key,
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let history = custom.history(key.as_deref());
+                    let max = max.unwrap_or_else(|| history.iter().copied().fold(0.0, f32::max));
+                    self.single_run_view(
+                        SimpleHistoryChart::new(history, max, *color)
+                            .thresholds(*thresholds)
+                            .fill(fill.clone()),
+                        format_custom_tooltip(custom, unit),
+                        *aspect_ratio,
+                    )
+                }
+                CustomView::Bar {
+                    key,
+                    color,
+                    thresholds,
+                    fill,
+                    aspect_ratio,
+                } => {
+                    let value = custom.value(key.as_deref()).map_or(0.0, |(_, value)| value);
+                    let max = max.unwrap_or_else(|| {
+                        let history = custom.history(key.as_deref());
+                        history.iter().copied().fold(value, f32::max)
+                    });
+                    let percentage = if max > 0.0 { value / max * 100.0 } else { 0.0 };
+                    self.single_bar_view(
+                        PercentageBar::new(self.is_horizontal(), percentage, *color)
+                            .thresholds(*thresholds)
+                            .fill(fill.clone()),
+                        format_custom_tooltip(custom, unit),
+                        *aspect_ratio,
+                    )
+                }
+            })
+            .collect()
+    }
 }